@@ -73,6 +73,7 @@ export const setClusterConfig = async (
 export const configProject = async () => {
   // Create a dummy config object to pass as argument.
   const newConfig = {
+    version: 2,
     authority: payer.publicKey,
     pendingAuthority: PublicKey.default,
 
@@ -95,6 +96,47 @@ export const configProject = async () => {
     initialized: true,
     global_authority: payer.publicKey,
     whitelist_enabled: false,
+
+    pendingAuthorityExpiry: new BN(0),
+    pendingGlobalAuthority: PublicKey.default,
+    pendingGlobalAuthorityExpiry: new BN(0),
+    pendingTeamWallet: PublicKey.default,
+    pendingTeamWalletExpiry: new BN(0),
+
+    creatorMerkleRoot: new Array(32).fill(0),
+    complianceAuthority: payer.publicKey,
+    vestingCliff: new BN(0),
+    vestingDuration: new BN(0),
+
+    minCurveBps: 0,
+    maxCreatorBps: 0,
+    maxTeamBps: 0,
+    maxAirdropBps: 0,
+    airdropClaimWindow: new BN(0),
+    collectionMint: PublicKey.default,
+    metadataRules: {
+      maxNameLen: 0,
+      maxSymbolLen: 0,
+      maxUriLen: 0,
+      asciiSymbolOnly: true,
+      uriPrefixes: ["https://", "ipfs://"],
+    },
+    registryCount: new BN(0),
+    launchCooldown: new BN(0),
+    launchWindow: new BN(0),
+    maxLaunchesPerWindow: 0,
+    launchFeeLamports: new BN(0),
+    refundLaunchFeeOnGraduation: false,
+    creatorGraduationRewardBps: 0,
+    completerGraduationRewardBps: 0,
+    migrationCrankRewardLamports: new BN(0),
+    migrationCostLamports: new BN(1_400_000_000),
+    marketCostLamports: new BN(300_000_000),
+    migrationTarget: { raydiumAmmV4: {} },
+    lpPolicy: { burn: {} },
+    lpLockDuration: new BN(0),
+
+    padding: new Array(44).fill(0),
   };
   const tx = await createConfigTx(
     payer.publicKey,
@@ -3,6 +3,7 @@ import { Cluster, PublicKey } from "@solana/web3.js";
 
 export const SEED_CONFIG = "config";
 export const SEED_BONDING_CURVE = "bonding_curve";
+export const SEED_LP_LOCK = "lp-lock";
 
 export const TEST_NAME = "test spl token";
 export const TEST_SYMBOL = "TEST";
@@ -13,6 +14,10 @@ export const TEST_TOKEN_SUPPLY = 1_000_000_000_000;
 export const TEST_DECIMALS = 6;
 export const TEST_INIT_BONDING_CURVE = 95;
 
+export const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
 const cluster: Cluster = "devnet";
 
 export const raydiumProgramId =
@@ -32,3 +37,19 @@ export const feeDestination =
   cluster.toString() == "mainnet-beta"
     ? new PublicKey("7YttLkHDoNj9wyDur5pM1ejNaAvT9X4eqaYcHQqtj2G5") // Mainnet
     : new PublicKey("3XMrhbv989VxAMi3DErLV9eJht1pHppW5LbKxe9fkEFR"); // Devnet
+
+export const cpmmProgram =
+  cluster.toString() == "mainnet-beta"
+    ? new PublicKey("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C") // mainnet-beta
+    : new PublicKey("CPMDWBwJDtYax9qW7AyRuVC19Cc4L4Vcy4n2BHAbHkCW"); // devnet
+
+//  amm config index 0
+export const cpmmConfig =
+  cluster.toString() == "mainnet-beta"
+    ? new PublicKey("D4FPEruKEHrG5TenZ2mpDGEfu1iUvTiqBxvpU8HLBvC2") // mainnet-beta
+    : new PublicKey("9zSzfkYy6awexsHvmggeH36pfVUdDGyCcwmjT3AQPBj6"); // devnet
+
+export const cpmmFeeDestination =
+  cluster.toString() == "mainnet-beta"
+    ? new PublicKey("DNXgeM9EiiaAbaWvwjHj9fQQLAX5ZsfHyvmYUNRAdNC8") // mainnet-beta
+    : new PublicKey("G11FKBRaAkHAKuLCgLM6K6NUc9rTjPAznRCjZifrTQe2"); // devnet
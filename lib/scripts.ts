@@ -12,13 +12,19 @@ import {
 import { Pumpfun } from "../target/types/pumpfun";
 import {
   ammProgram,
+  BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+  cpmmConfig,
+  cpmmFeeDestination,
+  cpmmProgram,
   feeDestination,
   marketProgram,
   SEED_BONDING_CURVE,
   SEED_CONFIG,
+  SEED_LP_LOCK,
 } from "./constant";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
   NATIVE_MINT,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
@@ -38,10 +44,17 @@ export const createConfigTx = async (
 
   console.log("configPda: ", configPda.toBase58());
 
+  //  first initialization is restricted to the upgrade authority
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  );
+
   const tx = await program.methods
     .configure(newConfig)
     .accounts({
       payer: admin,
+      programData,
     })
     .transaction();
 
@@ -101,12 +114,28 @@ export const launchTokenTx = async (
       //  metadata
       name,
       symbol,
-      uri
+      uri,
+
+      //  creator merkle proof
+      [],
+
+      //  token-2022 transfer fee
+      null,
+
+      //  allocation table, config split
+      null,
+
+      //  airdrop, none without an airdrop share
+      null,
+
+      //  migration target, config default
+      null
     )
     .accounts({
       creator: user,
       token: tokenKp.publicKey,
       teamWallet: user,
+      tokenProgram: TOKEN_PROGRAM_ID,
     })
     .transaction();
 
@@ -133,13 +162,22 @@ export const swapTx = async (
     program.programId
   );
   const configAccount = await program.account.config.fetch(configPda);
+  const [bondingCurvePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_BONDING_CURVE), token.toBytes()],
+    program.programId
+  );
+  const bondingCurveAccount = await program.account.bondingCurve.fetch(
+    bondingCurvePda
+  );
 
   const tx = await program.methods
     .swap(new BN(amount), style, new BN(amount))
     .accounts({
       teamWallet: configAccount.teamWallet,
+      creator: bondingCurveAccount.creator,
       user,
       tokenMint: token,
+      tokenProgram: TOKEN_PROGRAM_ID,
     })
     .transaction();
 
@@ -166,6 +204,7 @@ export const withdrawTx = async (
     .withdraw()
     .accounts({
       tokenMint: token,
+      tokenProgram: TOKEN_PROGRAM_ID,
     })
     .transaction();
 
@@ -175,6 +214,26 @@ export const withdrawTx = async (
   return tx;
 };
 
+//  lock pda of the curve and its lp ata, only passed when the config locks the lp tokens
+const lpLockAccounts = (
+  configAccount: any,
+  token: PublicKey,
+  lpMint: PublicKey,
+  program: Program<Pumpfun>
+) => {
+  if (!configAccount.lpPolicy.lock) {
+    return { lpLock: null, lpLockTokenAccount: null };
+  }
+  const lpLock = PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_LP_LOCK), token.toBytes()],
+    program.programId
+  )[0];
+  return {
+    lpLock,
+    lpLockTokenAccount: getAssociatedTokenAddressSync(lpMint, lpLock, true),
+  };
+};
+
 export const migrateTx = async (
   payer: PublicKey,
   token: PublicKey,
@@ -189,18 +248,14 @@ export const migrateTx = async (
   )[0];
   const configAccount = await program.account.config.fetch(configPda);
 
-  const nonce = PublicKey.findProgramAddressSync(
-    [Buffer.from("amm authority")],
-    ammProgram
-  )[1];
-
-  console.log("nonce: ", nonce);
-
   const bondingCurve = PublicKey.findProgramAddressSync(
     [Buffer.from(SEED_BONDING_CURVE), token.toBytes()],
     program.programId
   )[0];
   console.log("bondingCurve: ", bondingCurve.toBase58());
+  const bondingCurveAccount = await program.account.bondingCurve.fetch(
+    bondingCurve
+  );
 
   const globalVault = PublicKey.findProgramAddressSync(
     [Buffer.from("global")],
@@ -208,6 +263,128 @@ export const migrateTx = async (
   )[0];
   console.log("globalVault: ", globalVault.toBase58());
 
+  //  raydium amm v4 pool accounts are derived from the market
+  const marketPda = (seed: string) =>
+    PublicKey.findProgramAddressSync(
+      [ammProgram.toBuffer(), market.toBuffer(), Buffer.from(seed)],
+      ammProgram
+    )[0];
+  const lpMint = marketPda("lp_mint_associated_seed");
+
+  const tx = new Transaction()
+    .add(ComputeBudgetProgram.setComputeUnitLimit({ units: 500_000 }))
+    .add(
+      await program.methods
+        .graduate()
+        .accounts({
+          settle: {
+            coinMint: token,
+            payer,
+            teamWallet: configAccount.teamWallet,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
+            creator: bondingCurveAccount.creator,
+            completer: bondingCurveAccount.completer.equals(PublicKey.default)
+              ? null
+              : bondingCurveAccount.completer,
+          },
+          ...lpLockAccounts(configAccount, token, lpMint, program),
+        })
+        .remainingAccounts(
+          [
+            ammProgram,
+            marketPda("amm_associated_seed"),
+            PublicKey.findProgramAddressSync(
+              [Buffer.from("amm authority")],
+              ammProgram
+            )[0],
+            marketPda("open_order_associated_seed"),
+            lpMint,
+            marketPda("coin_vault_associated_seed"),
+            marketPda("pc_vault_associated_seed"),
+            marketPda("target_associated_seed"),
+            PublicKey.findProgramAddressSync(
+              [Buffer.from("amm_config_account_seed")],
+              ammProgram
+            )[0],
+            feeDestination,
+            marketProgram,
+            market,
+            getAssociatedTokenAddressSync(lpMint, globalVault, true),
+          ].map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: ![ammProgram, marketProgram].some((p) => p.equals(pubkey)),
+          }))
+        )
+        .transaction()
+    );
+
+  tx.feePayer = payer;
+  tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+
+  return tx;
+};
+
+export const migrateCpmmTx = async (
+  payer: PublicKey,
+  token: PublicKey,
+
+  connection: Connection,
+  program: Program<Pumpfun>
+) => {
+  const configPda = PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_CONFIG)],
+    program.programId
+  )[0];
+  const configAccount = await program.account.config.fetch(configPda);
+
+  const bondingCurve = PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_BONDING_CURVE), token.toBytes()],
+    program.programId
+  )[0];
+  const bondingCurveAccount = await program.account.bondingCurve.fetch(
+    bondingCurve
+  );
+
+  //  cpmm orders the pool sides by mint address
+  const [token0, token1] =
+    Buffer.compare(token.toBuffer(), NATIVE_MINT.toBuffer()) < 0
+      ? [token, NATIVE_MINT]
+      : [NATIVE_MINT, token];
+  const poolState = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("pool"),
+      cpmmConfig.toBuffer(),
+      token0.toBuffer(),
+      token1.toBuffer(),
+    ],
+    cpmmProgram
+  )[0];
+  const lpMint = PublicKey.findProgramAddressSync(
+    [Buffer.from("pool_lp_mint"), poolState.toBuffer()],
+    cpmmProgram
+  )[0];
+  const [coinVault, pcVault] = [token, NATIVE_MINT].map(
+    (mint) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("pool_vault"), poolState.toBuffer(), mint.toBuffer()],
+        cpmmProgram
+      )[0]
+  );
+  const observationState = PublicKey.findProgramAddressSync(
+    [Buffer.from("observation"), poolState.toBuffer()],
+    cpmmProgram
+  )[0];
+  const authority = PublicKey.findProgramAddressSync(
+    [Buffer.from("vault_and_lp_mint_auth_seed")],
+    cpmmProgram
+  )[0];
+  const globalVault = PublicKey.findProgramAddressSync(
+    [Buffer.from("global")],
+    program.programId
+  )[0];
+
   const tx = new Transaction()
     .add(ComputeBudgetProgram.setComputeUnitLimit({ units: 500_000 }))
     .add(
@@ -217,13 +394,47 @@ export const migrateTx = async (
           coinMint: token,
           payer,
           teamWallet: configAccount.teamWallet,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          creator: bondingCurveAccount.creator,
+          completer: bondingCurveAccount.completer.equals(PublicKey.default)
+            ? null
+            : bondingCurveAccount.completer,
         })
         .transaction()
     )
     .add(
       await program.methods
-        .migrate(nonce)
-        .accounts({ })
+        .migrate()
+        .accounts({
+          coinMint: token,
+          pcMint: NATIVE_MINT,
+          payer,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          ...lpLockAccounts(configAccount, token, lpMint, program),
+        })
+        .remainingAccounts(
+          [
+            cpmmProgram,
+            cpmmConfig,
+            authority,
+            poolState,
+            lpMint,
+            coinVault,
+            pcVault,
+            observationState,
+            cpmmFeeDestination,
+            getAssociatedTokenAddressSync(lpMint, globalVault, true),
+            TOKEN_PROGRAM_ID,
+          ].map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: ![cpmmProgram, cpmmConfig, authority, TOKEN_PROGRAM_ID].some(
+              (p) => p.equals(pubkey)
+            ),
+          }))
+        )
         .transaction()
     );
 
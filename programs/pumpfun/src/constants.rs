@@ -1,6 +1,18 @@
+use anchor_lang::prelude::*;
+
 pub const CONFIG: &str = "config";
 pub const GLOBAL: &str = "global";
 pub const BONDING_CURVE: &str = "bonding_curve";
 pub const METADATA: &str = "metadata";
 pub const LAMPORT_DECIMALS: u8 = 9;
-pub const TOKEN_LAUNCH: &str = "token_launch";
\ No newline at end of file
+pub const TOKEN_LAUNCH: &str = "token_launch";
+pub const QUOTE_CONFIG: &str = "quote_config";
+pub const AIRDROP: &str = "airdrop";
+pub const COLLECTION: &str = "collection";
+
+//  highest token-2022 transfer fee of a mint launched or opened with `list_existing`
+pub const MAX_TRANSFER_FEE_BPS: u16 = 100;
+
+//  wallet allowed to create the config when the program has no upgrade authority
+//  the default key can never sign, so only the upgrade authority works until this is set
+pub const DEPLOYER: Pubkey = Pubkey::new_from_array([0; 32]);
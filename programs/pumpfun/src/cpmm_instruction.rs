@@ -0,0 +1,157 @@
+//! Raydium CPMM instruction types
+
+#![allow(clippy::too_many_arguments)]
+
+use anchor_spl::{associated_token::spl_associated_token_account, token::spl_token};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+use std::mem::size_of;
+
+solana_program::declare_id!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+pub const AUTH_SEED: &str = "vault_and_lp_mint_auth_seed";
+pub const POOL_SEED: &str = "pool";
+pub const POOL_LP_MINT_SEED: &str = "pool_lp_mint";
+pub const POOL_VAULT_SEED: &str = "pool_vault";
+pub const OBSERVATION_SEED: &str = "observation";
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InitializeInstruction {
+    /// init token 0 amount
+    pub init_amount_0: u64,
+    /// init token 1 amount
+    pub init_amount_1: u64,
+    /// utc timestamps for pool open
+    pub open_time: u64,
+}
+
+/// Instructions supported by the CPMM program.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum CpmmInstruction {
+    ///   Initializes a new pool, token 0 mint should be lower than token 1 mint.
+    ///
+    ///   0. `[writable, signer]` Creator paying for the pool
+    ///   1. `[]` Amm config
+    ///   2. `[]` Vault and lp mint authority
+    ///   3. `[writable]` Pool state
+    ///   4. `[]` Token 0 mint
+    ///   5. `[]` Token 1 mint
+    ///   6. `[writable]` Lp mint
+    ///   7. `[writable]` Creator token 0 account
+    ///   8. `[writable]` Creator token 1 account
+    ///   9. `[writable]` Creator lp token account
+    ///   10. `[writable]` Token 0 vault
+    ///   11. `[writable]` Token 1 vault
+    ///   12. `[writable]` Pool creation fee receiver
+    ///   13. `[writable]` Observation state
+    ///   14. `[]` Spl token program, owner of the lp mint
+    ///   15. `[]` Token 0 program
+    ///   16. `[]` Token 1 program
+    ///   17. `[]` Associated token program
+    ///   18. `[]` System program
+    ///   19. `[]` Rent sysvar
+    Initialize(InitializeInstruction),
+}
+
+impl CpmmInstruction {
+    /// Anchor discriminator of `initialize`, sha256("global:initialize")[..8]
+    pub const INITIALIZE_DISCRIMINATOR: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+
+    /// Serializes an CpmmInstruction into a byte buffer.
+    pub fn pack(&self) -> Result<Vec<u8>, ProgramError> {
+        let mut buf = Vec::with_capacity(8 + size_of::<Self>());
+        match self {
+            Self::Initialize(InitializeInstruction {
+                init_amount_0,
+                init_amount_1,
+                open_time,
+            }) => {
+                buf.extend_from_slice(&Self::INITIALIZE_DISCRIMINATOR);
+                buf.extend_from_slice(&init_amount_0.to_le_bytes());
+                buf.extend_from_slice(&init_amount_1.to_le_bytes());
+                buf.extend_from_slice(&open_time.to_le_bytes());
+            }
+        }
+        Ok(buf)
+    }
+}
+
+/// Orders two mints the way the CPMM program expects them.
+pub fn sort_mints(mint_a: Pubkey, mint_b: Pubkey) -> (Pubkey, Pubkey) {
+    if mint_a < mint_b {
+        (mint_a, mint_b)
+    } else {
+        (mint_b, mint_a)
+    }
+}
+
+/// Creates an 'initialize' instruction.
+pub fn initialize(
+    cpmm_program: &Pubkey,
+    creator: &Pubkey,
+    amm_config: &Pubkey,
+    authority: &Pubkey,
+    pool_state: &Pubkey,
+    token_0_mint: &Pubkey,
+    token_1_mint: &Pubkey,
+    lp_mint: &Pubkey,
+    creator_token_0: &Pubkey,
+    creator_token_1: &Pubkey,
+    creator_lp_token: &Pubkey,
+    token_0_vault: &Pubkey,
+    token_1_vault: &Pubkey,
+    create_pool_fee: &Pubkey,
+    observation_state: &Pubkey,
+    token_0_program: &Pubkey,
+    token_1_program: &Pubkey,
+    init_amount_0: u64,
+    init_amount_1: u64,
+    open_time: u64,
+) -> Result<Instruction, ProgramError> {
+    let data = CpmmInstruction::Initialize(InitializeInstruction {
+        init_amount_0,
+        init_amount_1,
+        open_time,
+    })
+    .pack()?;
+
+    let accounts = vec![
+        // creator
+        AccountMeta::new(*creator, true),
+        // pool
+        AccountMeta::new_readonly(*amm_config, false),
+        AccountMeta::new_readonly(*authority, false),
+        AccountMeta::new(*pool_state, false),
+        AccountMeta::new_readonly(*token_0_mint, false),
+        AccountMeta::new_readonly(*token_1_mint, false),
+        AccountMeta::new(*lp_mint, false),
+        // creator tokens
+        AccountMeta::new(*creator_token_0, false),
+        AccountMeta::new(*creator_token_1, false),
+        AccountMeta::new(*creator_lp_token, false),
+        // vaults
+        AccountMeta::new(*token_0_vault, false),
+        AccountMeta::new(*token_1_vault, false),
+        AccountMeta::new(*create_pool_fee, false),
+        AccountMeta::new(*observation_state, false),
+        // programs
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(*token_0_program, false),
+        AccountMeta::new_readonly(*token_1_program, false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *cpmm_program,
+        accounts,
+        data,
+    })
+}
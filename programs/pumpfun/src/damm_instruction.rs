@@ -0,0 +1,161 @@
+//! Meteora dynamic amm instruction types
+
+#![allow(clippy::too_many_arguments)]
+
+use anchor_spl::{associated_token::spl_associated_token_account, token::spl_token};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+use std::mem::size_of;
+
+solana_program::declare_id!("Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB");
+
+/// Meteora vault program holding the pool liquidity
+pub mod vault_program {
+    solana_program::declare_id!("24Uqj9JCLxUeoC3hGfh5W3s9FM9uCHDS2SG3LYwBpyTi");
+}
+
+pub const LP_MINT_SEED: &str = "lp_mint";
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InitializeWithConfigInstruction {
+    /// init token a amount
+    pub token_a_amount: u64,
+    /// init token b amount
+    pub token_b_amount: u64,
+}
+
+/// Instructions supported by the dynamic amm program.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DammInstruction {
+    ///   Initializes a permissionless constant product pool with the fees of a config.
+    ///
+    ///   0. `[writable]` Pool
+    ///   1. `[]` Pool config
+    ///   2. `[writable]` Lp mint
+    ///   3. `[]` Token a mint
+    ///   4. `[]` Token b mint
+    ///   5. `[writable]` Vault of token a
+    ///   6. `[writable]` Vault of token b
+    ///   7. `[writable]` Token account of vault a
+    ///   8. `[writable]` Token account of vault b
+    ///   9. `[writable]` Lp mint of vault a
+    ///   10. `[writable]` Lp mint of vault b
+    ///   11. `[writable]` Vault a lp held by the pool
+    ///   12. `[writable]` Vault b lp held by the pool
+    ///   13. `[writable]` Payer token a account
+    ///   14. `[writable]` Payer token b account
+    ///   15. `[writable]` Payer pool lp account
+    ///   16. `[writable]` Protocol fee account of token a
+    ///   17. `[writable]` Protocol fee account of token b
+    ///   18. `[writable, signer]` Payer
+    ///   19. `[]` Rent sysvar
+    ///   20. `[writable]` Metadata of the lp mint
+    ///   21. `[]` Token metadata program
+    ///   22. `[]` Vault program
+    ///   23. `[]` Spl token program
+    ///   24. `[]` Associated token program
+    ///   25. `[]` System program
+    InitializeWithConfig(InitializeWithConfigInstruction),
+}
+
+impl DammInstruction {
+    /// Anchor discriminator of `initialize_permissionless_constant_product_pool_with_config`
+    pub const INITIALIZE_WITH_CONFIG_DISCRIMINATOR: [u8; 8] =
+        [7, 166, 138, 171, 206, 171, 236, 244];
+
+    /// Serializes an DammInstruction into a byte buffer.
+    pub fn pack(&self) -> Result<Vec<u8>, ProgramError> {
+        let mut buf = Vec::with_capacity(8 + size_of::<Self>());
+        match self {
+            Self::InitializeWithConfig(InitializeWithConfigInstruction {
+                token_a_amount,
+                token_b_amount,
+            }) => {
+                buf.extend_from_slice(&Self::INITIALIZE_WITH_CONFIG_DISCRIMINATOR);
+                buf.extend_from_slice(&token_a_amount.to_le_bytes());
+                buf.extend_from_slice(&token_b_amount.to_le_bytes());
+            }
+        }
+        Ok(buf)
+    }
+}
+
+/// Creates an 'initialize_permissionless_constant_product_pool_with_config' instruction.
+pub fn initialize_with_config(
+    damm_program: &Pubkey,
+    pool: &Pubkey,
+    config: &Pubkey,
+    lp_mint: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    a_vault: &Pubkey,
+    b_vault: &Pubkey,
+    a_token_vault: &Pubkey,
+    b_token_vault: &Pubkey,
+    a_vault_lp_mint: &Pubkey,
+    b_vault_lp_mint: &Pubkey,
+    a_vault_lp: &Pubkey,
+    b_vault_lp: &Pubkey,
+    payer_token_a: &Pubkey,
+    payer_token_b: &Pubkey,
+    payer_pool_lp: &Pubkey,
+    protocol_token_a_fee: &Pubkey,
+    protocol_token_b_fee: &Pubkey,
+    payer: &Pubkey,
+    mint_metadata: &Pubkey,
+    metadata_program: &Pubkey,
+    token_a_amount: u64,
+    token_b_amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let data = DammInstruction::InitializeWithConfig(InitializeWithConfigInstruction {
+        token_a_amount,
+        token_b_amount,
+    })
+    .pack()?;
+
+    let accounts = vec![
+        // pool
+        AccountMeta::new(*pool, false),
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*lp_mint, false),
+        AccountMeta::new_readonly(*token_a_mint, false),
+        AccountMeta::new_readonly(*token_b_mint, false),
+        // vaults
+        AccountMeta::new(*a_vault, false),
+        AccountMeta::new(*b_vault, false),
+        AccountMeta::new(*a_token_vault, false),
+        AccountMeta::new(*b_token_vault, false),
+        AccountMeta::new(*a_vault_lp_mint, false),
+        AccountMeta::new(*b_vault_lp_mint, false),
+        AccountMeta::new(*a_vault_lp, false),
+        AccountMeta::new(*b_vault_lp, false),
+        // payer
+        AccountMeta::new(*payer_token_a, false),
+        AccountMeta::new(*payer_token_b, false),
+        AccountMeta::new(*payer_pool_lp, false),
+        AccountMeta::new(*protocol_token_a_fee, false),
+        AccountMeta::new(*protocol_token_b_fee, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        // lp metadata
+        AccountMeta::new(*mint_metadata, false),
+        AccountMeta::new_readonly(*metadata_program, false),
+        // programs
+        AccountMeta::new_readonly(vault_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *damm_program,
+        accounts,
+        data,
+    })
+}
@@ -57,4 +57,169 @@ pub enum PumpfunError {
     
     #[msg("IncorrectLaunchPhase")]
     IncorrectLaunchPhase,
+
+    #[msg("Fee percentage must be between 0 and 100")]
+    InvalidFeePercent,
+
+    #[msg("Init bonding curve percentage must be above 0 and at most 100")]
+    InvalidInitBondingCurve,
+
+    #[msg("Curve limit must be greater than zero")]
+    InvalidCurveLimit,
+
+    #[msg("Amount config is invalid")]
+    InvalidAmountConfig,
+
+    #[msg("Only the upgrade authority or deployer can initialize the config")]
+    UnauthorizedInitializer,
+
+    #[msg("There is no pending nomination for this role")]
+    NoPendingNomination,
+
+    #[msg("The nomination has expired")]
+    NominationExpired,
+
+    #[msg("Account layout is outdated, migrate it first")]
+    OutdatedAccountLayout,
+
+    #[msg("Account layout version is not supported")]
+    UnsupportedLayoutVersion,
+
+    #[msg("This wallet is blocked")]
+    WalletBlocked,
+
+    #[msg("Invalid compliance authority")]
+    InvalidComplianceAuthority,
+
+    #[msg("Transfer fee extension requires the token-2022 program")]
+    TransferFeeRequiresToken2022,
+
+    #[msg("Metaplex metadata accounts are required for spl token launches")]
+    MissingMetadataAccounts,
+
+    #[msg("Quote mint is not approved for this curve")]
+    InvalidQuoteMint,
+
+    #[msg("Quote token accounts are missing or invalid")]
+    InvalidQuoteAccount,
+
+    #[msg("Vesting cliff should not be negative or exceed the duration")]
+    InvalidVestingSchedule,
+
+    #[msg("No vested tokens to claim")]
+    NothingToClaim,
+
+    #[msg("Allocation table should add up to 10000 basis points within the configured bounds")]
+    InvalidAllocation,
+
+    #[msg("Airdrop params are missing or invalid for the allocation")]
+    InvalidAirdrop,
+
+    #[msg("Airdrop is already claimed for this index")]
+    AirdropAlreadyClaimed,
+
+    #[msg("Invalid airdrop merkle proof")]
+    InvalidAirdropProof,
+
+    #[msg("Airdrop claim window has ended")]
+    AirdropExpired,
+
+    #[msg("Airdrop claim window has not ended yet")]
+    AirdropNotExpired,
+
+    #[msg("Platform collection is already created")]
+    CollectionAlreadyCreated,
+
+    #[msg("Collection accounts are required once the platform collection is created")]
+    MissingCollectionAccounts,
+
+    #[msg("Metadata rules exceed the metaplex limits")]
+    InvalidMetadataRules,
+
+    #[msg("Name is empty or longer than allowed")]
+    NameTooLong,
+
+    #[msg("Symbol is empty or longer than allowed")]
+    SymbolTooLong,
+
+    #[msg("Symbol should only contain printable ASCII characters")]
+    SymbolNotAscii,
+
+    #[msg("Uri is longer than allowed")]
+    UriTooLong,
+
+    #[msg("Uri scheme or host is not allowed")]
+    UriPrefixNotAllowed,
+
+    #[msg("Registry page of the curve is required")]
+    MissingRegistryPage,
+
+    #[msg("Launch cooldown and window should not be negative")]
+    InvalidLaunchLimits,
+
+    #[msg("Creator launched too recently")]
+    LaunchCooldown,
+
+    #[msg("Creator reached the launch limit of the current window")]
+    LaunchRateLimited,
+
+    #[msg("Graduation rewards exceed the allowed share of the proceeds")]
+    InvalidGraduationReward,
+
+    #[msg("Crank reward exceeds the allowed maximum")]
+    InvalidCrankReward,
+
+    #[msg("Migration fees are already settled")]
+    FeeAlreadySettled,
+
+    #[msg("Migration fees are not settled yet")]
+    FeeNotSettled,
+
+    #[msg("Curve is already migrated")]
+    AlreadyMigrated,
+
+    #[msg("Migration cost should cover the market cost and stay below the curve limit")]
+    InvalidMigrationCost,
+
+    #[msg("Curve proceeds don't cover migration fees, rewards and costs")]
+    InsufficientProceedsForMigration,
+
+    #[msg("Curve can't migrate to this target")]
+    InvalidMigrationTarget,
+
+    #[msg("Accounts of the migration target are missing")]
+    MissingMigrationAccounts,
+
+    #[msg("Account doesn't match the migration target")]
+    InvalidMigrationAccount,
+
+    #[msg("Lp lock duration should not be negative")]
+    InvalidLpLockDuration,
+
+    #[msg("Lp lock accounts are required to lock the lp tokens")]
+    MissingLpLock,
+
+    #[msg("Lp tokens are still locked")]
+    LpLocked,
+
+    #[msg("Metadata isn't updatable by the program, so it can't be locked on migration")]
+    MetadataNotLockable,
+
+    #[msg("Mint has an extension which can't be listed")]
+    UnsupportedMintExtension,
+
+    #[msg("Mint extension authority should be revoked")]
+    MintExtensionAuthorityEnabled,
+
+    #[msg("Transfer fee of the mint is too high")]
+    TransferFeeTooHigh,
+
+    #[msg("Curve limit should be above the curve reserve")]
+    CurveLimitBelowReserve,
+
+    #[msg("Config field can only be changed by its setter")]
+    ConfigFieldNotConfigurable,
+
+    #[msg("Registry page doesn't hold the entry of the curve")]
+    InvalidRegistryPage,
 }
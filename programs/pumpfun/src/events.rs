@@ -1,17 +1,128 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{AllocationTable, AuthorityRole, LpPolicy, MigrationTarget};
+
+#[event]
+pub struct ConfigInitialized {
+    pub initializer: Pubkey,
+    pub authority: Pubkey,
+    pub global_authority: Pubkey,
+    pub team_wallet: Pubkey,
+}
+
+#[event]
+pub struct CollectionCreated {
+    pub collection_mint: Pubkey,
+    pub collection_metadata: Pubkey,
+}
+
+#[event]
+pub struct AuthorityNominated {
+    pub role: AuthorityRole,
+    pub current: Pubkey,
+    pub nominee: Pubkey,
+    pub expiry: i64,
+}
+
+#[event]
+pub struct AuthorityAccepted {
+    pub role: AuthorityRole,
+    pub previous: Pubkey,
+    pub new: Pubkey,
+}
+
+#[event]
+pub struct AuthorityNominationCancelled {
+    pub role: AuthorityRole,
+    pub nominee: Pubkey,
+}
+
+#[event]
+pub struct BlocklistAdded {
+    pub wallet: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct BlocklistRemoved {
+    pub wallet: Pubkey,
+    pub authority: Pubkey,
+}
+
 #[event]
 pub struct LaunchEvent {
     pub creator: Pubkey,
     pub mint: Pubkey,
     pub bonding_curve: Pubkey,
     pub metadata: Pubkey,
+    pub token_program: Pubkey,
+    //  default pubkey for SOL curves
+    pub quote_mint: Pubkey,
 
     pub decimals: u8,
     pub token_supply: u64,
 
     pub reserve_lamport: u64,
     pub reserve_token: u64,
+    pub allocation: AllocationTable,
+    pub launch_fee: u64,
+    pub migration_target: MigrationTarget,
+
+    //  team allocation vesting, default pubkey when the allocation isn't vested
+    pub vesting: Pubkey,
+    pub vesting_beneficiary: Pubkey,
+    pub vesting_amount: u64,
+    pub vesting_start_ts: i64,
+    pub vesting_cliff_ts: i64,
+    pub vesting_end_ts: i64,
+}
+
+#[event]
+pub struct VestingClaimEvent {
+    pub mint: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub total_amount: u64,
+}
+
+#[event]
+pub struct AirdropClaimEvent {
+    pub mint: Pubkey,
+    pub claimant: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AirdropClawbackEvent {
+    pub mint: Pubkey,
+    pub admin: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MetadataUpdatedEvent {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct MetadataLockedEvent {
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+}
+
+#[event]
+pub struct LaunchFeeReleased {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    //  true when refunded to the creator, false when forwarded to the team wallet
+    pub refunded: bool,
 }
 
 #[event]
@@ -34,6 +145,38 @@ pub struct CompleteEvent {
     pub user: Pubkey,
     pub mint: Pubkey,
     pub bonding_curve: Pubkey,
+
+    //  graduation rewards paid at migration, the completer is `user`
+    pub creator: Pubkey,
+    pub creator_reward: u64,
+    pub completer_reward: u64,
+}
+
+#[event]
+pub struct GraduationRewardsPaid {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+    pub creator_reward: u64,
+    pub completer: Pubkey,
+    pub completer_reward: u64,
+}
+
+//  where the proceeds of a graduating curve go, in lamports or quote tokens
+#[event]
+pub struct MigrationCostBreakdown {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub proceeds: u64,
+    pub platform_fee: u64,
+    pub creator_reward: u64,
+    pub completer_reward: u64,
+    pub crank_reward: u64,
+    pub market_cost: u64,
+    pub pool_cost: u64,
+    pub pool_quote_amount: u64,
+    pub pool_token_amount: u64,
+    pub fee_token: u64,
 }
 
 #[event]
@@ -50,5 +193,18 @@ pub struct MigrateEvent {
     pub bonding_curve: Pubkey,
     pub token_in: u64,
     pub sol_in: u64,
-    pub lp_mint: Pubkey
+    pub lp_mint: Pubkey,
+
+    //  lp tokens burned or locked per `lp_policy`, unlock timestamp 0 when burned or locked forever
+    pub lp_amount: u64,
+    pub lp_policy: LpPolicy,
+    pub lp_unlock_ts: i64,
+}
+
+#[event]
+pub struct LpUnlockEvent {
+    pub mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
 }
\ No newline at end of file
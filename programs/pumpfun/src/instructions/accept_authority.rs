@@ -1,14 +1,17 @@
 use constants::CONFIG;
 use errors::PumpfunError;
+use events::AuthorityAccepted;
+use state::AuthorityRole;
 
 use crate::*;
 
 #[derive(Accounts)]
+#[instruction(role: AuthorityRole)]
 pub struct AcceptAuthority<'info> {
     //  Pending admin
     #[account(
         mut,
-        constraint = global_config.pending_authority == new_admin.key() @PumpfunError::IncorrectAuthority
+        constraint = global_config.pending_nomination(role).0 == new_admin.key() @PumpfunError::IncorrectAuthority
     )]
     pub new_admin: Signer<'info>,
 
@@ -22,9 +25,22 @@ pub struct AcceptAuthority<'info> {
 }
 
 impl AcceptAuthority<'_> {
-    pub fn process(&mut self) -> Result<()> {
-        self.global_config.authority = self.new_admin.key();
-        self.global_config.pending_authority = Pubkey::default();
+    pub fn process(&mut self, role: AuthorityRole) -> Result<()> {
+        let (_, expiry) = self.global_config.pending_nomination(role);
+        if expiry != 0 {
+            require!(
+                Clock::get()?.unix_timestamp <= expiry,
+                PumpfunError::NominationExpired
+            );
+        }
+
+        let previous = self.global_config.accept_nomination(role);
+
+        emit!(AuthorityAccepted {
+            role,
+            previous,
+            new: self.new_admin.key(),
+        });
 
         Ok(())
     }
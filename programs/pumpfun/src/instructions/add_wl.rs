@@ -1,6 +1,7 @@
 use crate::*;
 
 use constants::CONFIG;
+use errors::PumpfunError;
 
 use crate::state::Whitelist;
 
@@ -19,7 +20,7 @@ pub struct AddWl<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32,
+        space = 8 + Whitelist::INIT_SPACE,
         seeds = [Whitelist::SEED_PREFIX.as_bytes(), new_creator.key().as_ref()],
         bump
     )]
@@ -27,7 +28,7 @@ pub struct AddWl<'info> {
     
     #[account(
         mut, 
-        // constraint = admin.key() == global_config.global_authority.key() @ PumpfunError::InvalidGlobalAuthority
+        constraint = admin.key() == global_config.global_authority.key() @ PumpfunError::InvalidGlobalAuthority
     )]
     pub admin: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -36,6 +37,7 @@ pub struct AddWl<'info> {
 impl AddWl<'_> {
     pub fn handler(ctx: Context<AddWl>, new_creator: Pubkey) -> Result<()> {
         let whitelist = &mut ctx.accounts.whitelist;
+        whitelist.version = Whitelist::VERSION;
         whitelist.creator = new_creator.key();
         Ok(())
     }
@@ -0,0 +1,46 @@
+use crate::*;
+
+use constants::CONFIG;
+use errors::PumpfunError;
+use events::BlocklistAdded;
+use state::Blocklist;
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct BlockWallet<'info> {
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Blocklist::INIT_SPACE,
+        seeds = [Blocklist::SEED_PREFIX.as_bytes(), wallet.as_ref()],
+        bump
+    )]
+    pub blocklist: Account<'info, Blocklist>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == global_config.compliance_authority @ PumpfunError::InvalidComplianceAuthority
+    )]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl BlockWallet<'_> {
+    pub fn process(&mut self, wallet: Pubkey) -> Result<()> {
+        self.blocklist.version = Blocklist::VERSION;
+        self.blocklist.wallet = wallet;
+
+        emit!(BlocklistAdded {
+            wallet,
+            authority: self.admin.key(),
+        });
+
+        Ok(())
+    }
+}
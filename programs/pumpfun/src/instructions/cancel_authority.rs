@@ -0,0 +1,41 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use events::AuthorityNominationCancelled;
+use state::AuthorityRole;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CancelAuthority<'info> {
+    // Current admin
+    #[account(
+        mut,
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores admin address
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl CancelAuthority<'_> {
+    pub fn process(&mut self, role: AuthorityRole) -> Result<()> {
+        let (nominee, _) = self.global_config.pending_nomination(role);
+        require!(
+            nominee != Pubkey::default(),
+            PumpfunError::NoPendingNomination
+        );
+
+        self.global_config
+            .set_pending_nomination(role, Pubkey::default(), 0);
+
+        emit!(AuthorityNominationCancelled { role, nominee });
+
+        Ok(())
+    }
+}
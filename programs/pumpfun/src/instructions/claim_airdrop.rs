@@ -0,0 +1,110 @@
+use crate::{
+    constants::AIRDROP,
+    errors::PumpfunError,
+    events::AirdropClaimEvent,
+    state::Distributor,
+    utils::{airdrop_leaf, token_transfer_with_signer, verify_merkle_proof},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct ClaimAirdrop<'info> {
+    #[account(mut)]
+    claimant: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [AIRDROP.as_bytes(), token_mint.key().as_ref()],
+        bump = distributor.bump,
+    )]
+    distributor: Box<Account<'info, Distributor>>,
+
+    /// CHECK: ata of distributor pda which holds the airdrop allocation
+    #[account(
+        mut,
+        seeds = [
+            distributor.key().as_ref(),
+            token_program.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = associated_token::ID
+    )]
+    distributor_token_account: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        associated_token::mint = token_mint,
+        associated_token::authority = claimant,
+        associated_token::token_program = token_program,
+    )]
+    claimant_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    token_program: Interface<'info, TokenInterface>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ClaimAirdrop<'info> {
+    pub fn process(&mut self, index: u32, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        let distributor = &mut self.distributor;
+
+        require!(
+            Clock::get()?.unix_timestamp < distributor.expiry_ts && !distributor.clawed_back,
+            PumpfunError::AirdropExpired
+        );
+        require!(index < distributor.num_recipients, PumpfunError::InvalidAirdropProof);
+        require!(!distributor.is_claimed(index), PumpfunError::AirdropAlreadyClaimed);
+
+        let leaf = airdrop_leaf(index, &self.claimant.key(), amount);
+        require!(
+            verify_merkle_proof(&proof, &distributor.merkle_root, &leaf),
+            PumpfunError::InvalidAirdropProof
+        );
+
+        distributor.set_claimed(index);
+        distributor.num_claimed += 1;
+        distributor.claimed_amount = distributor
+            .claimed_amount
+            .checked_add(amount)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        let mint_key = self.token_mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            AIRDROP.as_bytes(),
+            mint_key.as_ref(),
+            &[distributor.bump],
+        ]];
+
+        token_transfer_with_signer(
+            self.distributor_token_account.clone(),
+            distributor.to_account_info(),
+            self.claimant_token_account.to_account_info(),
+            &self.token_mint,
+            &self.token_program,
+            signer_seeds,
+            amount,
+        )?;
+
+        emit!(AirdropClaimEvent {
+            mint: mint_key,
+            claimant: self.claimant.key(),
+            index,
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    errors::PumpfunError,
+    events::VestingClaimEvent,
+    state::Vesting,
+    utils::token_transfer_with_signer,
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    beneficiary: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [Vesting::SEED_PREFIX.as_bytes(), token_mint.key().as_ref()],
+        bump = vesting.bump,
+        has_one = beneficiary @PumpfunError::IncorrectAuthority,
+    )]
+    vesting: Box<Account<'info, Vesting>>,
+
+    /// CHECK: ata of vesting pda which holds the locked tokens
+    #[account(
+        mut,
+        seeds = [
+            vesting.key().as_ref(),
+            token_program.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = associated_token::ID
+    )]
+    vesting_token_account: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = token_mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    beneficiary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    token_program: Interface<'info, TokenInterface>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ClaimVested<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let vesting = &mut self.vesting;
+
+        let now = Clock::get()?.unix_timestamp;
+        let amount = vesting.claimable_amount(now);
+        require!(amount > 0, PumpfunError::NothingToClaim);
+
+        vesting.claimed_amount = vesting
+            .claimed_amount
+            .checked_add(amount)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        let mint_key = self.token_mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            Vesting::SEED_PREFIX.as_bytes(),
+            mint_key.as_ref(),
+            &[vesting.bump],
+        ]];
+
+        token_transfer_with_signer(
+            self.vesting_token_account.clone(),
+            vesting.to_account_info(),
+            self.beneficiary_token_account.to_account_info(),
+            &self.token_mint,
+            &self.token_program,
+            signer_seeds,
+            amount,
+        )?;
+
+        emit!(VestingClaimEvent {
+            mint: mint_key,
+            beneficiary: self.beneficiary.key(),
+            amount,
+            claimed_amount: vesting.claimed_amount,
+            total_amount: vesting.total_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    constants::{AIRDROP, CONFIG},
+    errors::PumpfunError,
+    events::AirdropClawbackEvent,
+    state::{Config, Distributor},
+    utils::token_transfer_with_signer,
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct ClawbackAirdrop<'info> {
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        constraint = global_config.authority == admin.key() @PumpfunError::IncorrectAuthority
+    )]
+    admin: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [AIRDROP.as_bytes(), token_mint.key().as_ref()],
+        bump = distributor.bump,
+    )]
+    distributor: Box<Account<'info, Distributor>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = distributor,
+        associated_token::token_program = token_program,
+    )]
+    distributor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = token_mint,
+        associated_token::authority = admin,
+        associated_token::token_program = token_program,
+    )]
+    admin_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    token_program: Interface<'info, TokenInterface>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ClawbackAirdrop<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let distributor = &mut self.distributor;
+
+        require!(
+            Clock::get()?.unix_timestamp >= distributor.expiry_ts,
+            PumpfunError::AirdropNotExpired
+        );
+        require!(!distributor.clawed_back, PumpfunError::AirdropExpired);
+
+        distributor.clawed_back = true;
+
+        let amount = self.distributor_token_account.amount;
+        let mint_key = self.token_mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            AIRDROP.as_bytes(),
+            mint_key.as_ref(),
+            &[distributor.bump],
+        ]];
+
+        token_transfer_with_signer(
+            self.distributor_token_account.to_account_info(),
+            distributor.to_account_info(),
+            self.admin_token_account.to_account_info(),
+            &self.token_mint,
+            &self.token_program,
+            signer_seeds,
+            amount,
+        )?;
+
+        emit!(AirdropClawbackEvent {
+            mint: mint_key,
+            admin: self.admin.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -1,10 +1,13 @@
 use crate::{
-    constants::{CONFIG, GLOBAL},
-    state::Config,
-    utils::sol_transfer_from_user,
+    constants::{CONFIG, DEPLOYER, GLOBAL},
+    events::ConfigInitialized,
+    state::{is_v0_layout, Config},
+    utils::{resize_account, sol_transfer_from_user},
 };
 use crate::errors::*;
-use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_lang::{
+    prelude::*, solana_program::bpf_loader_upgradeable, system_program, Discriminator,
+};
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token, TokenAccount},
@@ -45,6 +48,10 @@ pub struct Configure<'info> {
     )]
     native_mint: Box<Account<'info, Mint>>,
 
+    //  program data of this program, required for the first initialization
+    //  unless the payer is the compiled-in deployer
+    program_data: Option<Account<'info, ProgramData>>,
+
     #[account(address = system_program::ID)]
     system_program: Program<'info, System>,
 
@@ -54,14 +61,59 @@ pub struct Configure<'info> {
 }
 
 impl<'info> Configure<'info> {
-    pub fn process(&mut self, new_config: Config, config_bump: u8) -> Result<()> {
+    pub fn process(&mut self, mut new_config: Config, config_bump: u8) -> Result<()> {
+        let is_initialized = self.config.owner == &crate::ID;
+
+        if is_initialized {
+            let data = self.config.try_borrow_data()?;
+            if data.len() < 8 || &data[0..8] != Config::DISCRIMINATOR {
+                return err!(PumpfunError::IncorrectConfigAccount);
+            }
+            if is_v0_layout(&data, &self.payer.key()) {
+                return err!(PumpfunError::OutdatedAccountLayout);
+            }
+            let config = Config::deserialize(&mut &data[8..])?;
+            if config.version != Config::VERSION {
+                return err!(PumpfunError::OutdatedAccountLayout);
+            }
+
+            if config.authority != self.payer.key() {
+                return err!(PumpfunError::IncorrectAuthority);
+            }
+            //  authorities are only handed over by nomination
+            if new_config.authority != config.authority {
+                return err!(PumpfunError::IncorrectAuthority);
+            }
+
+            //  fields owned by dedicated setters, the nomination flow or the program
+            //  should be sent unchanged, so nothing is dropped silently
+            let unchanged = Config {
+                version: Config::VERSION,
+                init_bonding_curve: config.init_bonding_curve,
+                initialized: config.initialized,
+                ..new_config.clone()
+            };
+            if unchanged.try_to_vec()? != config.try_to_vec()? {
+                msg!("only init bonding curve and initialized can change, use the setters for the rest");
+                return err!(PumpfunError::ConfigFieldNotConfigurable);
+            }
+        } else {
+            new_config.collection_mint = Pubkey::default();
+            new_config.registry_count = 0;
+        }
+
+        new_config.validate()?;
+        new_config.version = Config::VERSION;
+
         let serialized_config =
             [&Config::DISCRIMINATOR, new_config.try_to_vec()?.as_slice()].concat();
         let serialized_config_len = serialized_config.len();
         let config_cost = Rent::get()?.minimum_balance(serialized_config_len);
 
         //  init config pda
-        if self.config.owner != &crate::ID {
+        if !is_initialized {
+            self.check_initializer()?;
+
             let cpi_context = CpiContext::new(
                 self.system_program.to_account_info(),
                 system_program::CreateAccount {
@@ -76,32 +128,21 @@ impl<'info> Configure<'info> {
                 serialized_config_len as u64,
                 &crate::ID,
             )?;
-        } else {
-            let data = self.config.try_borrow_data()?;
-            if data.len() < 8 || &data[0..8] != Config::DISCRIMINATOR {
-                return err!(PumpfunError::IncorrectConfigAccount);
-            }
-            let config = Config::deserialize(&mut &data[8..])?;
 
-            if config.authority != self.payer.key() {
-                return err!(PumpfunError::IncorrectAuthority);
-            }
+            emit!(ConfigInitialized {
+                initializer: self.payer.key(),
+                authority: new_config.authority,
+                global_authority: new_config.global_authority,
+                team_wallet: new_config.team_wallet,
+            });
         }
 
-        let lamport_delta = (config_cost as i64) - (self.config.lamports() as i64);
-        if lamport_delta > 0 {
-            system_program::transfer(
-                CpiContext::new(
-                    self.system_program.to_account_info(),
-                    system_program::Transfer {
-                        from: self.payer.to_account_info(),
-                        to: self.config.to_account_info(),
-                    },
-                ),
-                lamport_delta as u64,
-            )?;
-            self.config.realloc(serialized_config_len, false)?;
-        }
+        resize_account(
+            &self.config,
+            &self.payer,
+            &self.system_program,
+            serialized_config_len,
+        )?;
 
         (self.config.try_borrow_mut_data()?[..serialized_config_len])
             .copy_from_slice(serialized_config.as_slice());
@@ -117,4 +158,31 @@ impl<'info> Configure<'info> {
         }
         Ok(())
     }
+
+    //  only the upgrade authority or the deployer can create the config
+    fn check_initializer(&self) -> Result<()> {
+        if DEPLOYER != Pubkey::default() && self.payer.key() == DEPLOYER {
+            return Ok(());
+        }
+
+        let program_data = self
+            .program_data
+            .as_ref()
+            .ok_or(PumpfunError::UnauthorizedInitializer)?;
+
+        let (program_data_address, _) =
+            Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+        require_keys_eq!(
+            program_data.key(),
+            program_data_address,
+            PumpfunError::UnauthorizedInitializer
+        );
+
+        require!(
+            program_data.upgrade_authority_address == Some(self.payer.key()),
+            PumpfunError::UnauthorizedInitializer
+        );
+
+        Ok(())
+    }
 }
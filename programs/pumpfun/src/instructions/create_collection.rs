@@ -0,0 +1,190 @@
+use crate::{
+    constants::{COLLECTION, CONFIG, GLOBAL, METADATA},
+    errors::PumpfunError,
+    events::CollectionCreated,
+    state::Config,
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    metadata::{
+        self,
+        mpl_token_metadata::types::{CollectionDetails, Creator, DataV2},
+        Metadata,
+    },
+    token::{self, Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct CreateCollection<'info> {
+    #[account(
+        mut,
+        constraint = global_config.authority == admin.key() @PumpfunError::IncorrectAuthority
+    )]
+    admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    /// CHECK: global vault pda, authority of the collection
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    global_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [COLLECTION.as_bytes()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = global_vault,
+        mint::freeze_authority = global_vault,
+        mint::token_program = token_program,
+    )]
+    collection_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = collection_mint,
+        associated_token::authority = global_vault,
+        associated_token::token_program = token_program,
+    )]
+    collection_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: created by token metadata program
+    #[account(
+        mut,
+        seeds = [
+            METADATA.as_bytes(),
+            metadata::ID.as_ref(),
+            collection_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = metadata::ID
+    )]
+    collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: created by token metadata program
+    #[account(
+        mut,
+        seeds = [
+            METADATA.as_bytes(),
+            metadata::ID.as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition",
+        ],
+        bump,
+        seeds::program = metadata::ID
+    )]
+    collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    token_program: Program<'info, Token>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    mpl_token_metadata_program: Program<'info, Metadata>,
+
+    rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> CreateCollection<'info> {
+    pub fn process(
+        &mut self,
+        name: String,
+        symbol: String,
+        uri: String,
+        global_vault_bump: u8,
+    ) -> Result<()> {
+        require!(
+            self.global_config.collection_mint == Pubkey::default(),
+            PumpfunError::CollectionAlreadyCreated
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::MintTo {
+                    mint: self.collection_mint.to_account_info(),
+                    to: self.collection_token_account.to_account_info(),
+                    authority: self.global_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                self.mpl_token_metadata_program.to_account_info(),
+                metadata::CreateMetadataAccountsV3 {
+                    metadata: self.collection_metadata.to_account_info(),
+                    mint: self.collection_mint.to_account_info(),
+                    mint_authority: self.global_vault.to_account_info(),
+                    payer: self.admin.to_account_info(),
+                    update_authority: self.global_vault.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    rent: self.rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            DataV2 {
+                name,
+                symbol,
+                uri,
+                seller_fee_basis_points: 0,
+                creators: Some(vec![Creator {
+                    address: self.global_vault.key(),
+                    verified: true,
+                    share: 100,
+                }]),
+                collection: None,
+                uses: None,
+            },
+            true,
+            true,
+            Some(CollectionDetails::V1 { size: 0 }),
+        )?;
+
+        //  max supply 0 makes the collection a one of one
+        metadata::create_master_edition_v3(
+            CpiContext::new_with_signer(
+                self.mpl_token_metadata_program.to_account_info(),
+                metadata::CreateMasterEditionV3 {
+                    edition: self.collection_master_edition.to_account_info(),
+                    mint: self.collection_mint.to_account_info(),
+                    update_authority: self.global_vault.to_account_info(),
+                    mint_authority: self.global_vault.to_account_info(),
+                    payer: self.admin.to_account_info(),
+                    metadata: self.collection_metadata.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    rent: self.rent.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            Some(0),
+        )?;
+
+        self.global_config.collection_mint = self.collection_mint.key();
+
+        emit!(CollectionCreated {
+            collection_mint: self.collection_mint.key(),
+            collection_metadata: self.collection_metadata.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::GLOBAL,
+    migration::{migrate_curve, MigrationCaller, PoolAccounts},
+    state::{LpLock, RegistryPage},
+};
+
+//  nested accounts need the modules generated next to `TransferFee`
+use super::transfer_fee::*;
+
+//  `transfer_fee` and `migrate` in one instruction, a failed pool creation rolls back the fees
+//  optional accounts of `settle` can be passed as the program id to keep the list short
+//  accounts of the curve's migration target follow as remaining accounts
+#[derive(Accounts)]
+pub struct Graduate<'info> {
+    pub settle: TransferFee<'info>,
+
+    //  registry page of the curve, only for registered curves
+    #[account(
+        mut,
+        seeds = [
+            RegistryPage::SEED_PREFIX.as_bytes(),
+            &RegistryPage::page_of(settle.bonding_curve.registry_index).to_le_bytes(),
+        ],
+        bump
+    )]
+    registry_page: Option<Box<Account<'info, RegistryPage>>>,
+
+    //  holds the lp tokens when the config locks them, only for `LpPolicy::Lock`
+    #[account(
+        init,
+        payer = settle.payer,
+        space = 8 + LpLock::INIT_SPACE,
+        seeds = [LpLock::SEED_PREFIX.as_bytes(), settle.coin_mint.key().as_ref()],
+        bump
+    )]
+    lp_lock: Option<Box<Account<'info, LpLock>>>,
+
+    /// CHECK: ata of lp_lock for the lp mint, checked and created after the pool
+    #[account(mut)]
+    lp_lock_token_account: Option<AccountInfo<'info>>,
+
+    //  `lp_lock` can't be created with the one of `settle`
+    pub system_program: Program<'info, System>,
+    pub sysvar_rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> Graduate<'info> {
+    pub fn process(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        global_vault_bump: u8,
+        lp_lock_bump: Option<u8>,
+    ) -> Result<()> {
+        //  fees, rewards and the wrapped pool side, same as `transfer_fee`
+        //  fails for curves already settled or migrated
+        self.settle.process(global_vault_bump)?;
+
+        let settle = &mut self.settle;
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        let pool = PoolAccounts {
+            global_vault: settle.global_vault.to_account_info(),
+            coin_mint: settle.coin_mint.to_account_info(),
+            pc_mint: settle.pc_mint.to_account_info(),
+            coin_account: settle.global_token_account.to_account_info(),
+            pc_account: settle.global_wsol_account.to_account_info(),
+            token_program: settle.token_program.to_account_info(),
+            quote_token_program: settle.quote_token_program.to_account_info(),
+            associated_token_program: settle.associated_token_program.to_account_info(),
+            system_program: settle.system_program.to_account_info(),
+            rent: self.sysvar_rent.to_account_info(),
+        };
+        let caller = MigrationCaller {
+            payer: settle.payer.to_account_info(),
+            lp_lock: self.lp_lock.as_deref_mut(),
+            lp_lock_token_account: self.lp_lock_token_account.clone(),
+            lp_lock_bump,
+        };
+
+        migrate_curve(
+            &settle.global_config,
+            &mut settle.bonding_curve,
+            self.registry_page.as_deref_mut().map(|page| &mut **page),
+            &pool,
+            caller,
+            &settle.system_program,
+            remaining_accounts,
+            signer_seeds,
+        )
+    }
+}
@@ -1,21 +1,40 @@
-use std::ops::{Div, Mul};
-
 use crate::{
-    constants::{BONDING_CURVE, CONFIG, GLOBAL, METADATA},
+    constants::{AIRDROP, BONDING_CURVE, CONFIG, GLOBAL, LAMPORT_DECIMALS, METADATA},
     errors::*,
     events::LaunchEvent,
-    state::{BondingCurve, Config},
-    state::Whitelist,
+    state::{
+        AirdropParams, AllocationTable, BondingCurve, Config, Distributor, MigrationTarget,
+        QuoteConfig, RegistryPage, TransferFeeParams, Vesting,
+    },
+    state::{Blocklist, CreatorProfile, Whitelist},
+    utils::{check_ata_address, create_ata_if_needed, sol_transfer_from_user},
+};
+use anchor_lang::{
+    prelude::*,
+    Discriminator,
+    solana_program::{program_pack::Pack, sysvar::SysvarId},
+    system_program,
 };
-use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId, system_program};
 use anchor_spl::{
     associated_token::{self, AssociatedToken},
-    metadata::{self, mpl_token_metadata::types::DataV2, Metadata},
-    token::{self, spl_token::instruction::AuthorityType, Mint, Token},
+    metadata::{
+        self,
+        mpl_token_metadata::types::{Collection, Creator, DataV2},
+        Metadata,
+    },
+    token::spl_token,
+    token_2022::{
+        self,
+        spl_token_2022::{self, extension::ExtensionType, instruction::AuthorityType},
+    },
+    token_2022_extensions::{
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::TokenMetadata,
+    },
+    token_interface::{self, Mint, TokenInterface},
 };
 
 #[derive(Accounts)]
-#[instruction(decimals: u8)]
 pub struct Launch<'info> {
     #[account(
         mut,
@@ -35,24 +54,33 @@ pub struct Launch<'info> {
     #[account(mut)]
     creator: Signer<'info>,
 
+    //  created in the instruction so token-2022 extensions can be chosen per launch
+    #[account(mut)]
+    token: Signer<'info>,
+
     #[account(
         init,
         payer = creator,
-        mint::decimals = decimals,
-        mint::authority = global_vault.key(),
+        space = 8 + BondingCurve::INIT_SPACE,
+        seeds = [BONDING_CURVE.as_bytes(), &token.key().to_bytes()],
+        bump
     )]
-    token: Box<Account<'info, Mint>>,
+    bonding_curve: Box<Account<'info, BondingCurve>>,
 
+    //  page the launch is appended to
     #[account(
-        init,
+        init_if_needed,
         payer = creator,
-        space = 8 + std::mem::size_of::<BondingCurve>(),
-        seeds = [BONDING_CURVE.as_bytes(), &token.key().to_bytes()],
+        space = 8 + RegistryPage::INIT_SPACE,
+        seeds = [
+            RegistryPage::SEED_PREFIX.as_bytes(),
+            &RegistryPage::page_of(global_config.registry_count).to_le_bytes(),
+        ],
         bump
     )]
-    bonding_curve: Box<Account<'info, BondingCurve>>,
+    registry_page: Box<Account<'info, RegistryPage>>,
 
-    /// CHECK: passed to token metadata program
+    /// CHECK: passed to token metadata program, only used by spl token launches
     #[account(
         mut,
         seeds = [
@@ -63,14 +91,14 @@ pub struct Launch<'info> {
         bump,
         seeds::program = metadata::ID
     )]
-    token_metadata_account: UncheckedAccount<'info>,
+    token_metadata_account: Option<UncheckedAccount<'info>>,
 
     /// CHECK: created in instruction
     #[account(
         mut,
         seeds = [
             global_vault.key().as_ref(),
-            token::spl_token::ID.as_ref(),
+            token_program.key().as_ref(),
             token.key().as_ref(),
         ],
         bump,
@@ -84,20 +112,47 @@ pub struct Launch<'info> {
     )]
     whitelist: Option<Account<'info, Whitelist>>,
 
+    /// CHECK: blocklist pda of creator, only exists if the creator is blocked
+    #[account(
+        seeds = [Blocklist::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump,
+    )]
+    creator_blocklist: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorProfile::INIT_SPACE,
+        seeds = [CreatorProfile::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump,
+    )]
+    creator_profile: Box<Account<'info, CreatorProfile>>,
+
     #[account(address = system_program::ID)]
     system_program: Program<'info, System>,
 
     #[account(address = Rent::id())]
     rent: Sysvar<'info, Rent>,
 
-    #[account(address = token::ID)]
-    token_program: Program<'info, Token>,
+    //  spl token or token-2022
+    token_program: Interface<'info, TokenInterface>,
 
     #[account(address = associated_token::ID)]
     associated_token_program: Program<'info, AssociatedToken>,
 
-    #[account(address = metadata::ID)]
-    mpl_token_metadata_program: Program<'info, Metadata>,
+    mpl_token_metadata_program: Option<Program<'info, Metadata>>,
+
+    //  platform collection, required for spl token launches once it is created
+    /// CHECK: checked against the config
+    #[account(address = global_config.collection_mint @PumpfunError::MissingCollectionAccounts)]
+    collection_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: verified by token metadata program
+    #[account(mut)]
+    collection_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: verified by token metadata program
+    collection_master_edition: Option<UncheckedAccount<'info>>,
 
     //  team wallet
     /// CHECK: should be same with the address in the global_config
@@ -107,18 +162,74 @@ pub struct Launch<'info> {
     )]
     pub team_wallet: AccountInfo<'info>,
 
-    /// CHECK: ata of team wallet
+    //  locks the team allocation, team wallet is the beneficiary
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Vesting::INIT_SPACE,
+        seeds = [Vesting::SEED_PREFIX.as_bytes(), token.key().as_ref()],
+        bump
+    )]
+    vesting: Box<Account<'info, Vesting>>,
+
+    /// CHECK: ata of vesting pda, created in instruction
+    #[account(
+        mut,
+        seeds = [
+            vesting.key().as_ref(),
+            token_program.key().as_ref(),
+            token.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    vesting_token_account: AccountInfo<'info>,
+
+    /// CHECK: ata of creator, created in instruction when the creator gets a share
     #[account(
         mut,
         seeds = [
-            team_wallet.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
+            creator.key().as_ref(),
+            token_program.key().as_ref(),
             token.key().as_ref(),
         ],
         bump,
         seeds::program = anchor_spl::associated_token::ID
     )]
-    team_wallet_ata: AccountInfo<'info>,
+    creator_token_account: AccountInfo<'info>,
+
+    /// CHECK: distributor pda which owns the airdrop allocation, created in instruction
+    #[account(
+        mut,
+        seeds = [AIRDROP.as_bytes(), token.key().as_ref()],
+        bump,
+    )]
+    distributor: AccountInfo<'info>,
+
+    /// CHECK: ata of distributor pda, created in instruction when the airdrop gets a share
+    #[account(
+        mut,
+        seeds = [
+            distributor.key().as_ref(),
+            token_program.key().as_ref(),
+            token.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    airdrop_token_account: AccountInfo<'info>,
+
+    //  quote accounts, only for curves priced in an spl quote mint
+    #[account(mint::token_program = quote_token_program)]
+    quote_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    quote_config: Option<Box<Account<'info, QuoteConfig>>>,
+
+    /// CHECK: quote ata of global vault, created in instruction
+    #[account(mut)]
+    global_quote_ata: Option<UncheckedAccount<'info>>,
+
+    quote_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 impl<'info> Launch<'info> {
@@ -134,26 +245,40 @@ impl<'info> Launch<'info> {
         name: String,
         symbol: String,
         uri: String,
+
+        creator_proof: Vec<[u8; 32]>,
+        transfer_fee: Option<TransferFeeParams>,
+        allocation: Option<AllocationTable>,
+        airdrop: Option<AirdropParams>,
+        migration_target: Option<MigrationTarget>,
         global_vault_bump: u8,
+        vesting_bump: u8,
+        distributor_bump: u8,
     ) -> Result<()> {
+        Blocklist::check_not_blocked(&self.creator_blocklist)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        self.creator_profile
+            .record_launch(self.creator.key(), &self.global_config, now)?;
+
+        if let Some(transfer_fee) = &transfer_fee {
+            if self.token_program.key() != token_2022::ID {
+                return err!(PumpfunError::TransferFeeRequiresToken2022);
+            }
+            transfer_fee.validate()?;
+        }
+
         let global_config = &self.global_config;
-        let creator = &self.creator;
-        let token = &self.token;
-        let global_token_account = &self.global_token_account;
-        let bonding_curve = &mut self.bonding_curve;
-        let global_vault = &self.global_vault;
-        let team_wallet = &mut self.team_wallet;
-        let team_wallet_ata = &self.team_wallet_ata;
+
+        //  fail before any account is paid for
+        global_config.metadata_rules.check(&name, &symbol, &uri)?;
 
         // validate whitelist
-        // if self.global_config.whitelist_enabled {
-        //     let whitelist = self.whitelist.as_ref();
-        //     require!(whitelist.is_some(), PumpfunError::NotWhiteList);
-        //     require!(
-        //         whitelist.unwrap().creator == self.creator.key(),
-        //         PumpfunError::NotWhiteList
-        //     );
-        // }
+        global_config.check_creator_whitelisted(
+            &self.creator.key(),
+            self.whitelist.as_deref(),
+            &creator_proof,
+        )?;
 
         //  check params
         let decimal_multiplier = 10u64.pow(decimals as u32);
@@ -163,9 +288,28 @@ impl<'info> Launch<'info> {
             return Err(ValueInvalid.into());
         }
 
-        global_config
-            .lamport_amount_config
-            .validate(&reserve_lamport)?;
+        match self.quote_mint.as_deref() {
+            Some(quote_mint) => {
+                let Some(quote_config) = self.quote_config.as_deref() else {
+                    return err!(PumpfunError::InvalidQuoteMint);
+                };
+                require!(
+                    quote_config.enabled && quote_config.mint == quote_mint.key(),
+                    PumpfunError::InvalidQuoteMint
+                );
+                quote_config.validate_init_reserve(reserve_lamport)?;
+            }
+            None => {
+                global_config
+                    .lamport_amount_config
+                    .validate(&reserve_lamport)?;
+                //  open ranges aren't bounded by the curve limit in config
+                require!(
+                    reserve_lamport < global_config.curve_limit,
+                    PumpfunError::CurveLimitBelowReserve
+                );
+            }
+        }
 
         global_config
             .token_supply_config
@@ -173,18 +317,58 @@ impl<'info> Launch<'info> {
 
         global_config.token_decimals_config.validate(&decimals)?;
 
-        let init_bonding_curve = (token_supply as f64)
-            .mul(global_config.init_bonding_curve)
-            .div(100_f64) as u64;
+        //  custom tables are bounded by config, the default is the config split itself
+        let allocation = match allocation {
+            Some(allocation) => {
+                allocation.validate(global_config)?;
+                allocation
+            }
+            None => AllocationTable::from_config(global_config),
+        };
+
+        //  not every dex can pool token-2022 mints
+        let migration_target = migration_target.unwrap_or(global_config.migration_target);
+        if !migration_target.supports_token_2022() && self.token_program.key() == token_2022::ID {
+            return err!(PumpfunError::InvalidMigrationTarget);
+        }
+
+        let init_bonding_curve = AllocationTable::amount_of(token_supply, allocation.curve_bps);
+        let amount_to_creator = AllocationTable::amount_of(token_supply, allocation.creator_bps);
+        let amount_to_airdrop = AllocationTable::amount_of(token_supply, allocation.airdrop_bps);
+
+        //  team takes the rounding dust
+        let amount_to_team = token_supply - init_bonding_curve - amount_to_creator - amount_to_airdrop;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        let is_token_2022 = self.token_program.key() == token_2022::ID;
+        self.create_mint(decimals, is_token_2022, transfer_fee, signer_seeds)?;
 
-        let amount_to_team = token_supply - init_bonding_curve;
+        let creator = &self.creator;
+        let token = &self.token;
+        let global_token_account = &self.global_token_account;
+        let bonding_curve = &mut self.bonding_curve;
+        let global_vault = &self.global_vault;
+        let vesting_token_account = &self.vesting_token_account;
 
         // create token launch pda
+        bonding_curve.version = BondingCurve::VERSION;
         bonding_curve.token_mint = token.key();
         bonding_curve.creator = creator.key();
         bonding_curve.init_lamport = reserve_lamport;
         bonding_curve.reserve_lamport = reserve_lamport;
         bonding_curve.reserve_token = init_bonding_curve;
+        bonding_curve.allocation = allocation;
+        match self.quote_mint.as_deref() {
+            Some(quote_mint) => {
+                bonding_curve.quote_mint = quote_mint.key();
+                bonding_curve.quote_decimals = quote_mint.decimals;
+            }
+            None => {
+                bonding_curve.quote_mint = Pubkey::default();
+                bonding_curve.quote_decimals = LAMPORT_DECIMALS;
+            }
+        }
 
         // create global token account
         associated_token::create(CpiContext::new(
@@ -198,26 +382,104 @@ impl<'info> Launch<'info> {
                 system_program: self.system_program.to_account_info(),
             },
         ))?;
-        // create team token account
-        anchor_spl::associated_token::create(CpiContext::new(
+        // create vesting token account
+        associated_token::create(CpiContext::new(
             self.associated_token_program.to_account_info(),
-            anchor_spl::associated_token::Create {
+            associated_token::Create {
                 payer: creator.to_account_info(),
-                associated_token: team_wallet_ata.to_account_info(),
-                authority: team_wallet.to_account_info(),
-
+                associated_token: vesting_token_account.to_account_info(),
+                authority: self.vesting.to_account_info(),
                 mint: token.to_account_info(),
-                system_program: self.system_program.to_account_info(),
                 token_program: self.token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
             },
         ))?;
-        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
 
-        // mint tokens to bonding curve & team
-        token::mint_to(
+        // create quote vault of the curve
+        if let Some(quote_mint) = self.quote_mint.as_deref() {
+            let (Some(global_quote_ata), Some(quote_token_program)) = (
+                self.global_quote_ata.as_ref(),
+                self.quote_token_program.as_ref(),
+            ) else {
+                return err!(PumpfunError::InvalidQuoteAccount);
+            };
+            check_ata_address(
+                global_quote_ata,
+                &global_vault.key(),
+                &quote_mint.key(),
+                &quote_token_program.key(),
+            )?;
+            create_ata_if_needed(
+                creator.to_account_info(),
+                global_quote_ata.to_account_info(),
+                global_vault.to_account_info(),
+                quote_mint.to_account_info(),
+                quote_token_program.to_account_info(),
+                self.system_program.to_account_info(),
+                self.associated_token_program.to_account_info(),
+            )?;
+        }
+
+        // create airdrop distributor
+        match airdrop {
+            Some(airdrop) if amount_to_airdrop > 0 => self.create_distributor(
+                airdrop,
+                amount_to_airdrop,
+                distributor_bump,
+            )?,
+            None if amount_to_airdrop == 0 => {}
+            _ => return err!(PumpfunError::InvalidAirdrop),
+        }
+
+        // mint creator & airdrop shares
+        for (token_account, authority, amount) in [
+            (&self.creator_token_account, creator.to_account_info(), amount_to_creator),
+            (&self.airdrop_token_account, self.distributor.clone(), amount_to_airdrop),
+        ] {
+            if amount == 0 {
+                continue;
+            }
+            create_ata_if_needed(
+                creator.to_account_info(),
+                token_account.clone(),
+                authority,
+                token.to_account_info(),
+                self.token_program.to_account_info(),
+                self.system_program.to_account_info(),
+                self.associated_token_program.to_account_info(),
+            )?;
+            token_interface::mint_to(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token_interface::MintTo {
+                        mint: token.to_account_info(),
+                        to: token_account.clone(),
+                        authority: global_vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+
+        // lock team allocation
+        let start_ts = Clock::get()?.unix_timestamp;
+        let vesting = &mut self.vesting;
+        vesting.version = Vesting::VERSION;
+        vesting.mint = token.key();
+        vesting.beneficiary = self.team_wallet.key();
+        vesting.total_amount = amount_to_team;
+        vesting.claimed_amount = 0;
+        vesting.start_ts = start_ts;
+        vesting.cliff_ts = start_ts + global_config.vesting_cliff;
+        vesting.end_ts = start_ts + global_config.vesting_duration;
+        vesting.bump = vesting_bump;
+
+        // mint tokens to bonding curve & vesting vault
+        token_interface::mint_to(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
-                token::MintTo {
+                token_interface::MintTo {
                     mint: token.to_account_info(),
                     to: global_token_account.to_account_info(),
                     authority: global_vault.to_account_info(),
@@ -226,12 +488,12 @@ impl<'info> Launch<'info> {
             ),
             init_bonding_curve,
         )?;
-        token::mint_to(
+        token_interface::mint_to(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
-                token::MintTo {
+                token_interface::MintTo {
                     mint: token.to_account_info(),
-                    to: team_wallet_ata.to_account_info(),
+                    to: vesting_token_account.to_account_info(),
                     authority: global_vault.to_account_info(),
                 },
                 signer_seeds,
@@ -240,15 +502,235 @@ impl<'info> Launch<'info> {
         )?;
 
         // create metadata
+        let metadata = if is_token_2022 {
+            self.create_token_2022_metadata(name, symbol, uri, signer_seeds)?
+        } else {
+            self.create_metaplex_metadata(name, symbol, uri, signer_seeds)?
+        };
+
+        //  revoke mint authority
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token_interface::SetAuthority {
+                    current_authority: global_vault.to_account_info(),
+                    account_or_mint: token.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        self.bonding_curve.is_completed = false;
+        self.bonding_curve.migration_target = migration_target;
+
+        //  escrow launch fee in the curve until it graduates
+        let launch_fee = self.global_config.launch_fee_lamports;
+        if launch_fee > 0 {
+            sol_transfer_from_user(
+                &self.creator,
+                self.bonding_curve.to_account_info(),
+                &self.system_program,
+                launch_fee,
+            )?;
+        }
+        self.bonding_curve.launch_fee_escrowed = launch_fee;
+        self.bonding_curve.refund_launch_fee = self.global_config.refund_launch_fee_on_graduation;
+
+        let bonding_curve_key = self.bonding_curve.key();
+        self.registry_page.register(
+            &mut self.global_config,
+            &mut self.bonding_curve,
+            bonding_curve_key,
+        )?;
+
+        emit!(LaunchEvent {
+            creator: self.creator.key(),
+            mint: self.token.key(),
+            bonding_curve: self.bonding_curve.key(),
+            metadata,
+            token_program: self.token_program.key(),
+            quote_mint: self.bonding_curve.quote_mint,
+
+            decimals,
+            token_supply,
+            reserve_lamport,
+            reserve_token: init_bonding_curve,
+            allocation,
+            launch_fee,
+            migration_target,
+
+            vesting: self.vesting.key(),
+            vesting_beneficiary: self.vesting.beneficiary,
+            vesting_amount: self.vesting.total_amount,
+            vesting_start_ts: self.vesting.start_ts,
+            vesting_cliff_ts: self.vesting.cliff_ts,
+            vesting_end_ts: self.vesting.end_ts,
+        });
+
+        Ok(())
+    }
+    //  distributor pda holds the claim bitmap, so its size depends on the recipients
+    fn create_distributor(
+        &self,
+        airdrop: AirdropParams,
+        total_amount: u64,
+        distributor_bump: u8,
+    ) -> Result<()> {
+        if airdrop.num_recipients == 0 || airdrop.num_recipients > Distributor::MAX_RECIPIENTS {
+            msg!(
+                "invalid airdrop recipients {:?}, expected 1 to {:?}",
+                airdrop.num_recipients,
+                Distributor::MAX_RECIPIENTS
+            );
+            return err!(PumpfunError::InvalidAirdrop);
+        }
+
+        let distributor = Distributor {
+            version: Distributor::VERSION,
+            mint: self.token.key(),
+            merkle_root: airdrop.merkle_root,
+            total_amount,
+            claimed_amount: 0,
+            num_recipients: airdrop.num_recipients,
+            num_claimed: 0,
+            expiry_ts: Clock::get()?.unix_timestamp + self.global_config.airdrop_claim_window,
+            clawed_back: false,
+            bump: distributor_bump,
+            padding: [0; 32],
+            claimed_bitmap: vec![0; Distributor::bitmap_len(airdrop.num_recipients)],
+        };
+
+        let serialized_distributor =
+            [&Distributor::DISCRIMINATOR, distributor.try_to_vec()?.as_slice()].concat();
+        let space = Distributor::space(airdrop.num_recipients);
+
+        let token_key = self.token.key();
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: self.creator.to_account_info(),
+                    to: self.distributor.to_account_info(),
+                },
+                &[&[AIRDROP.as_bytes(), token_key.as_ref(), &[distributor_bump]]],
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        )?;
+
+        (self.distributor.try_borrow_mut_data()?[..serialized_distributor.len()])
+            .copy_from_slice(serialized_distributor.as_slice());
+
+        Ok(())
+    }
+
+    //  create the mint owned by the chosen token program, with token-2022 extensions if needed
+    fn create_mint(
+        &self,
+        decimals: u8,
+        is_token_2022: bool,
+        transfer_fee: Option<TransferFeeParams>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let space = if is_token_2022 {
+            let mut extensions = vec![ExtensionType::MetadataPointer];
+            if transfer_fee.is_some() {
+                extensions.push(ExtensionType::TransferFeeConfig);
+            }
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions)?
+        } else {
+            spl_token::state::Mint::LEN
+        };
+
+        system_program::create_account(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: self.creator.to_account_info(),
+                    to: self.token.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &self.token_program.key(),
+        )?;
+
+        if is_token_2022 {
+            //  metadata lives in the mint itself
+            token_interface::metadata_pointer_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    token_interface::MetadataPointerInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.token.to_account_info(),
+                    },
+                ),
+                Some(self.global_vault.key()),
+                Some(self.token.key()),
+            )?;
+
+            if let Some(transfer_fee) = transfer_fee {
+                //  fee config is immutable, withheld fees belong to the creator
+                token_interface::transfer_fee_initialize(
+                    CpiContext::new(
+                        self.token_program.to_account_info(),
+                        token_interface::TransferFeeInitialize {
+                            token_program_id: self.token_program.to_account_info(),
+                            mint: self.token.to_account_info(),
+                        },
+                    ),
+                    None,
+                    Some(&self.creator.key()),
+                    transfer_fee.basis_points,
+                    transfer_fee.maximum_fee,
+                )?;
+            }
+        }
+
+        token_interface::initialize_mint2(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token_interface::InitializeMint2 {
+                    mint: self.token.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            decimals,
+            &self.global_vault.key(),
+            None,
+        )
+    }
+
+    //  returns the metadata address
+    fn create_metaplex_metadata(
+        &self,
+        name: String,
+        symbol: String,
+        uri: String,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<Pubkey> {
+        let (Some(token_metadata_account), Some(mpl_token_metadata_program)) = (
+            self.token_metadata_account.as_ref(),
+            self.mpl_token_metadata_program.as_ref(),
+        ) else {
+            return err!(PumpfunError::MissingMetadataAccounts);
+        };
+
+        let collection_mint = self.global_config.collection_mint;
+        let has_collection = collection_mint != Pubkey::default();
+
         metadata::create_metadata_accounts_v3(
             CpiContext::new_with_signer(
-                self.mpl_token_metadata_program.to_account_info(),
+                mpl_token_metadata_program.to_account_info(),
                 metadata::CreateMetadataAccountsV3 {
-                    metadata: self.token_metadata_account.to_account_info(),
-                    mint: token.to_account_info(),
-                    mint_authority: global_vault.to_account_info(),
-                    payer: creator.to_account_info(),
-                    update_authority: global_vault.to_account_info(),
+                    metadata: token_metadata_account.to_account_info(),
+                    mint: self.token.to_account_info(),
+                    mint_authority: self.global_vault.to_account_info(),
+                    payer: self.creator.to_account_info(),
+                    update_authority: self.global_vault.to_account_info(),
                     system_program: self.system_program.to_account_info(),
                     rent: self.rent.to_account_info(),
                 },
@@ -259,43 +741,106 @@ impl<'info> Launch<'info> {
                 symbol,
                 uri,
                 seller_fee_basis_points: 0,
-                creators: None,
-                collection: None,
+                //  platform signs as creator so launches can be told apart from copies
+                creators: Some(vec![Creator {
+                    address: self.global_vault.key(),
+                    verified: true,
+                    share: 100,
+                }]),
+                collection: has_collection.then_some(Collection {
+                    verified: false,
+                    key: collection_mint,
+                }),
                 uses: None,
             },
-            false,
+            //  mutable until migration so the creator can fix it with update_metadata
+            true,
             true,
             None,
         )?;
 
-        //  revoke mint authority
-        token::set_authority(
+        if has_collection {
+            let (Some(collection_mint), Some(collection_metadata), Some(collection_master_edition)) = (
+                self.collection_mint.as_ref(),
+                self.collection_metadata.as_ref(),
+                self.collection_master_edition.as_ref(),
+            ) else {
+                return err!(PumpfunError::MissingCollectionAccounts);
+            };
+
+            metadata::verify_sized_collection_item(
+                CpiContext::new_with_signer(
+                    mpl_token_metadata_program.to_account_info(),
+                    metadata::VerifySizedCollectionItem {
+                        payer: self.creator.to_account_info(),
+                        metadata: token_metadata_account.to_account_info(),
+                        collection_authority: self.global_vault.to_account_info(),
+                        collection_mint: collection_mint.to_account_info(),
+                        collection_metadata: collection_metadata.to_account_info(),
+                        collection_master_edition: collection_master_edition.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                None,
+            )?;
+        }
+
+        Ok(token_metadata_account.key())
+    }
+
+    //  returns the metadata address, which is the mint itself
+    fn create_token_2022_metadata(
+        &self,
+        name: String,
+        symbol: String,
+        uri: String,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<Pubkey> {
+        let token_metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey::try_from(Some(self.global_vault.key()))?,
+            mint: self.token.key(),
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            additional_metadata: vec![],
+        };
+
+        //  token-2022 reallocs the mint for the metadata, so fund the extra rent first
+        let mint_info = self.token.to_account_info();
+        let new_len = mint_info.data_len() + token_metadata.tlv_size_of()?;
+        let lamport_delta = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(mint_info.lamports());
+        if lamport_delta > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: self.creator.to_account_info(),
+                        to: mint_info,
+                    },
+                ),
+                lamport_delta,
+            )?;
+        }
+
+        token_interface::token_metadata_initialize(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
-                token::SetAuthority {
-                    current_authority: global_vault.to_account_info(),
-                    account_or_mint: token.to_account_info(),
+                token_interface::TokenMetadataInitialize {
+                    token_program_id: self.token_program.to_account_info(),
+                    metadata: self.token.to_account_info(),
+                    update_authority: self.global_vault.to_account_info(),
+                    mint_authority: self.global_vault.to_account_info(),
+                    mint: self.token.to_account_info(),
                 },
                 signer_seeds,
             ),
-            AuthorityType::MintTokens,
-            None,
+            name,
+            symbol,
+            uri,
         )?;
 
-        bonding_curve.is_completed = false;
-
-        emit!(LaunchEvent {
-            creator: self.creator.key(),
-            mint: self.token.key(),
-            bonding_curve: self.bonding_curve.key(),
-            metadata: self.token_metadata_account.key(),
-
-            decimals,
-            token_supply,
-            reserve_lamport,
-            reserve_token: init_bonding_curve
-        });
-
-        Ok(())
+        Ok(self.token.key())
     }
 }
@@ -0,0 +1,329 @@
+use crate::{
+    constants::{BONDING_CURVE, CONFIG, GLOBAL, LAMPORT_DECIMALS},
+    errors::*,
+    events::LaunchEvent,
+    state::{AllocationTable, BondingCurve, Config, MigrationTarget, QuoteConfig, RegistryPage},
+    state::{Blocklist, CreatorProfile, Whitelist},
+    utils::{
+        check_ata_address, check_listed_mint_extensions, create_ata_if_needed,
+        sol_transfer_from_user, token_transfer_user, transfer_fee_of,
+    },
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token_2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct ListExisting<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        mut,
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = token_mint.mint_authority.is_none() @PumpfunError::MintAuthorityEnabled,
+        constraint = token_mint.freeze_authority.is_none() @PumpfunError::FreezeAuthorityEnabled,
+    )]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + BondingCurve::INIT_SPACE,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    //  page the launch is appended to
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + RegistryPage::INIT_SPACE,
+        seeds = [
+            RegistryPage::SEED_PREFIX.as_bytes(),
+            &RegistryPage::page_of(global_config.registry_count).to_le_bytes(),
+        ],
+        bump
+    )]
+    registry_page: Box<Account<'info, RegistryPage>>,
+
+    //  holds the curve allocation
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: created in instruction
+    #[account(
+        mut,
+        seeds = [
+            global_vault.key().as_ref(),
+            token_program.key().as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = associated_token::ID
+    )]
+    global_token_account: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [Whitelist::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump,
+    )]
+    whitelist: Option<Account<'info, Whitelist>>,
+
+    /// CHECK: blocklist pda of creator, only exists if the creator is blocked
+    #[account(
+        seeds = [Blocklist::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump,
+    )]
+    creator_blocklist: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorProfile::INIT_SPACE,
+        seeds = [CreatorProfile::SEED_PREFIX.as_bytes(), creator.key().as_ref()],
+        bump,
+    )]
+    creator_profile: Box<Account<'info, CreatorProfile>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    //  spl token or token-2022
+    token_program: Interface<'info, TokenInterface>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    //  quote accounts, only for curves priced in an spl quote mint
+    #[account(mint::token_program = quote_token_program)]
+    quote_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    quote_config: Option<Box<Account<'info, QuoteConfig>>>,
+
+    /// CHECK: quote ata of global vault, created in instruction
+    #[account(mut)]
+    global_quote_ata: Option<UncheckedAccount<'info>>,
+
+    quote_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> ListExisting<'info> {
+    pub fn process(&mut self, reserve_lamport: u64, creator_proof: Vec<[u8; 32]>) -> Result<()> {
+        Blocklist::check_not_blocked(&self.creator_blocklist)?;
+        check_listed_mint_extensions(&self.token_mint)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        self.creator_profile
+            .record_launch(self.creator.key(), &self.global_config, now)?;
+
+        let global_config = &self.global_config;
+
+        // validate whitelist
+        global_config.check_creator_whitelisted(
+            &self.creator.key(),
+            self.whitelist.as_deref(),
+            &creator_proof,
+        )?;
+
+        //  check params
+        let decimals = self.token_mint.decimals;
+        let token_supply = self.token_mint.supply;
+        let decimal_multiplier = 10u64.pow(decimals as u32);
+        let fractional_tokens = token_supply % decimal_multiplier;
+        if fractional_tokens != 0 {
+            msg!("expected whole number of tokens, got fractional tokens: 0.{fractional_tokens}");
+            return Err(ValueInvalid.into());
+        }
+
+        match self.quote_mint.as_deref() {
+            Some(quote_mint) => {
+                let Some(quote_config) = self.quote_config.as_deref() else {
+                    return err!(PumpfunError::InvalidQuoteMint);
+                };
+                require!(
+                    quote_config.enabled && quote_config.mint == quote_mint.key(),
+                    PumpfunError::InvalidQuoteMint
+                );
+                quote_config.validate_init_reserve(reserve_lamport)?;
+            }
+            None => {
+                global_config
+                    .lamport_amount_config
+                    .validate(&reserve_lamport)?;
+                //  open ranges aren't bounded by the curve limit in config
+                require!(
+                    reserve_lamport < global_config.curve_limit,
+                    PumpfunError::CurveLimitBelowReserve
+                );
+            }
+        }
+
+        global_config
+            .token_supply_config
+            .validate(&(token_supply / decimal_multiplier))?;
+
+        global_config.token_decimals_config.validate(&decimals)?;
+
+        //  config split, the rest of the supply stays with the creator
+        let config_allocation = AllocationTable::from_config(global_config);
+        let allocation = AllocationTable {
+            creator_bps: AllocationTable::TOTAL_BPS - config_allocation.curve_bps,
+            curve_bps: config_allocation.curve_bps,
+            ..Default::default()
+        };
+        let init_bonding_curve = AllocationTable::amount_of(token_supply, allocation.curve_bps);
+
+        // create global token account
+        associated_token::create(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: self.creator.to_account_info(),
+                associated_token: self.global_token_account.to_account_info(),
+                authority: self.global_vault.to_account_info(),
+                mint: self.token_mint.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        ))?;
+
+        // create quote vault of the curve
+        if let Some(quote_mint) = self.quote_mint.as_deref() {
+            let (Some(global_quote_ata), Some(quote_token_program)) = (
+                self.global_quote_ata.as_ref(),
+                self.quote_token_program.as_ref(),
+            ) else {
+                return err!(PumpfunError::InvalidQuoteAccount);
+            };
+            check_ata_address(
+                global_quote_ata,
+                &self.global_vault.key(),
+                &quote_mint.key(),
+                &quote_token_program.key(),
+            )?;
+            create_ata_if_needed(
+                self.creator.to_account_info(),
+                global_quote_ata.to_account_info(),
+                self.global_vault.to_account_info(),
+                quote_mint.to_account_info(),
+                quote_token_program.to_account_info(),
+                self.system_program.to_account_info(),
+                self.associated_token_program.to_account_info(),
+            )?;
+        }
+
+        // pull the curve allocation from the creator
+        token_transfer_user(
+            self.creator_token_account.to_account_info(),
+            &self.creator,
+            self.global_token_account.to_account_info(),
+            &self.token_mint,
+            &self.token_program,
+            init_bonding_curve,
+        )?;
+
+        //  token-2022 transfer fee is withheld in the vault
+        let reserve_token = init_bonding_curve - transfer_fee_of(&self.token_mint, init_bonding_curve)?;
+
+        // create token launch pda
+        let bonding_curve = &mut self.bonding_curve;
+        bonding_curve.version = BondingCurve::VERSION;
+        bonding_curve.token_mint = self.token_mint.key();
+        bonding_curve.creator = self.creator.key();
+        bonding_curve.init_lamport = reserve_lamport;
+        bonding_curve.reserve_lamport = reserve_lamport;
+        bonding_curve.reserve_token = reserve_token;
+        bonding_curve.allocation = allocation;
+        match self.quote_mint.as_deref() {
+            Some(quote_mint) => {
+                bonding_curve.quote_mint = quote_mint.key();
+                bonding_curve.quote_decimals = quote_mint.decimals;
+            }
+            None => {
+                bonding_curve.quote_mint = Pubkey::default();
+                bonding_curve.quote_decimals = LAMPORT_DECIMALS;
+            }
+        }
+        bonding_curve.is_completed = false;
+        //  not every dex can pool token-2022 mints, raydium cpmm can
+        bonding_curve.migration_target = match self.global_config.migration_target {
+            migration_target
+                if !migration_target.supports_token_2022()
+                    && self.token_program.key() == token_2022::ID =>
+            {
+                MigrationTarget::RaydiumCpmm
+            }
+            migration_target => migration_target,
+        };
+
+        //  escrow launch fee in the curve until it graduates
+        let launch_fee = self.global_config.launch_fee_lamports;
+        if launch_fee > 0 {
+            sol_transfer_from_user(
+                &self.creator,
+                self.bonding_curve.to_account_info(),
+                &self.system_program,
+                launch_fee,
+            )?;
+        }
+        self.bonding_curve.launch_fee_escrowed = launch_fee;
+        self.bonding_curve.refund_launch_fee = self.global_config.refund_launch_fee_on_graduation;
+
+        let bonding_curve_key = self.bonding_curve.key();
+        self.registry_page.register(
+            &mut self.global_config,
+            &mut self.bonding_curve,
+            bonding_curve_key,
+        )?;
+
+        emit!(LaunchEvent {
+            creator: self.creator.key(),
+            mint: self.token_mint.key(),
+            bonding_curve: self.bonding_curve.key(),
+            //  existing mints bring their own metadata
+            metadata: Pubkey::default(),
+            token_program: self.token_program.key(),
+            quote_mint: self.bonding_curve.quote_mint,
+
+            decimals,
+            token_supply,
+            reserve_lamport,
+            reserve_token,
+            allocation,
+            launch_fee,
+            migration_target: self.bonding_curve.migration_target,
+
+            vesting: Pubkey::default(),
+            vesting_beneficiary: Pubkey::default(),
+            vesting_amount: 0,
+            vesting_start_ts: 0,
+            vesting_cliff_ts: 0,
+            vesting_end_ts: 0,
+        });
+
+        Ok(())
+    }
+}
@@ -1,137 +1,21 @@
-use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{amm_instruction, constants::{CONFIG, GLOBAL, TOKEN_LAUNCH}, errors::PumpfunError, state::{Config, LaunchPhase, TokenLaunch, TokenLaunchAccount}};
-
-pub fn migrate(
-    ctx: Context<Migrate>,
-    nonce: u8
-) -> Result<()> {
-    let token_launch = &mut ctx.accounts.token_launch;
-    
-    //  check launch phase is completed
-    token_launch.launch_phase.assert_eq(&LaunchPhase::Completed)?;
-
-
-    let fee_lamport = token_launch.reserve_lamport / 10000 * ctx.accounts.global_config.platform_migration_fee as u64;
-
-    //  0.32 - market create, 0.4 - pool create, 0.01 - tx
-    let init_pc_amount = token_launch.reserve_lamport - fee_lamport - token_launch.init_lamport - 730_000_000;
-
-    let coin_amount = (init_pc_amount as u128 * token_launch.reserve_token as u128 / token_launch.reserve_lamport as u128) as u64; 
-    let fee_token = token_launch.reserve_token - coin_amount;
-
-    //  transfer 0.33 SOL to signer for market id creation fee + tx fee
-    token_launch.transfer_sol_from_pool(
-        &ctx.accounts.global_vault,
-        &ctx.accounts.user_wallet,
-        330_000_000, 
-        &ctx.accounts.system_program,
-        ctx.bumps.global_vault
-    )?;
-
-    //  transfer fee lamport and fee token to admin wallet
-    token_launch.transfer_sol_from_pool(
-        &ctx.accounts.global_vault,
-        &ctx.accounts.admin,
-        fee_lamport,
-        &ctx.accounts.system_program,
-        ctx.bumps.global_vault
-    )?;
-    token_launch.transfer_token_from_pool(
-        &ctx.accounts.global_token_account,
-        &ctx.accounts.admin_token_account,
-        fee_token,
-        &ctx.accounts.token_program,
-        &ctx.accounts.global_vault,
-        ctx.bumps.global_vault
-    )?;
-
-
-    let seeds = &[
-        GLOBAL.as_bytes(), 
-        &[ctx.bumps.global_vault]
-    ];
-    let signed_seeds = &[&seeds[..]];
-
-
-    //  Running raydium amm initialize2
-    let initialize_ix = amm_instruction::initialize2(
-        ctx.accounts.amm_program.key,
-        ctx.accounts.amm.key,
-        ctx.accounts.amm_authority.key,
-        ctx.accounts.amm_open_orders.key,
-        ctx.accounts.lp_mint.key,
-        &ctx.accounts.coin_mint.key(),
-        &ctx.accounts.pc_mint.key(),
-        ctx.accounts.coin_vault.key,
-        ctx.accounts.pc_vault.key,
-        ctx.accounts.target_orders.key,
-        ctx.accounts.amm_config.key,
-        ctx.accounts.admin.key,
-        ctx.accounts.market_program.key,
-        ctx.accounts.market.key,
-        //  change this to PDA address
-        ctx.accounts.global_vault.key,
-        ctx.accounts.global_token_account.key,
-        ctx.accounts.user_token_pc.key,
-        &ctx.accounts.user_token_lp.key(),
-        nonce,
-        Clock::get()?.unix_timestamp as u64,
-        init_pc_amount,
-        coin_amount,
-    )?;
-    let account_infos = [
-        ctx.accounts.amm_program.clone(),
-        ctx.accounts.amm.clone(),
-        ctx.accounts.amm_authority.clone(),
-        ctx.accounts.amm_open_orders.clone(),
-        ctx.accounts.lp_mint.clone(),
-        ctx.accounts.coin_mint.to_account_info().clone(),
-        ctx.accounts.pc_mint.to_account_info().clone(),
-        ctx.accounts.coin_vault.clone(),
-        ctx.accounts.pc_vault.clone(),
-        ctx.accounts.target_orders.clone(),
-        ctx.accounts.amm_config.clone(),
-        ctx.accounts.admin.clone(),
-        ctx.accounts.market_program.clone(),
-        ctx.accounts.market.clone(),
-        ctx.accounts.global_vault.clone(),
-        ctx.accounts.global_token_account.clone(),
-        ctx.accounts.user_token_pc.clone(),
-        ctx.accounts.user_token_lp.clone(),
-        ctx.accounts.token_program.to_account_info().clone(),
-        ctx.accounts.system_program.to_account_info().clone(),
-        ctx.accounts
-            .associated_token_program
-            .to_account_info()
-            .clone(),
-        ctx.accounts.sysvar_rent.to_account_info().clone(),
-    ];
-    invoke_signed(&initialize_ix, &account_infos, signed_seeds)?;
-
-    msg!("Reserve:: Token: {:?}  Sol: {:?}", token_launch.reserve_token, token_launch.reserve_lamport);
-    msg!("Raydium Input:: Token: {:?}  Sol: {:?}", coin_amount, init_pc_amount);
-    
-    //  update reserves
-    token_launch.update_reserves(&*ctx.accounts.global_config, 0, 0)?;
-
-    Ok(())
-}
+use crate::{
+    constants::{BONDING_CURVE, CONFIG, GLOBAL},
+    errors::PumpfunError,
+    migration::{migrate_curve, MigrationCaller, PoolAccounts},
+    state::{BondingCurve, Config, LpLock, RegistryPage},
+};
 
+//  anyone can create the pool once `transfer_fee` settled the curve
+//  the caller is paid the crank reward held back by `transfer_fee`
+//  accounts of the curve's migration target follow as remaining accounts
 #[derive(Accounts)]
 pub struct Migrate<'info> {
-
-    /// CHECK: Safe
-    #[account(
-        mut,
-        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
-    )]
-    pub admin: AccountInfo<'info>,
-
     #[account(
         seeds = [CONFIG.as_bytes()],
         bump,
@@ -140,138 +24,62 @@ pub struct Migrate<'info> {
 
     #[account(
         mut,
-        seeds = [TOKEN_LAUNCH.as_bytes(), &coin_mint.key().to_bytes()],
+        seeds = [BONDING_CURVE.as_bytes(), &coin_mint.key().to_bytes()],
         bump
     )]
-    token_launch: Box<Account<'info, TokenLaunch>>,
-
-    /// CHECK
-    #[account(
-        mut,
-        seeds = [GLOBAL.as_bytes()],
-        bump,
-    )]
-    pub global_vault: AccountInfo<'info>,
+    bonding_curve: Box<Account<'info, BondingCurve>>,
 
-    /// CHECK: Safe
-    pub amm_program: AccountInfo<'info>,
-    /// CHECK: Safe. The spl token program
-    pub token_program: Program<'info, Token>,
-    /// CHECK: Safe. The associated token program
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    /// CHECK: Safe. System program
-    pub system_program: Program<'info, System>,
-    /// CHECK: Safe. Rent program
-    pub sysvar_rent: Sysvar<'info, Rent>,
-    /// CHECK: Safe.
-    #[account(
-        mut,
-        seeds = [
-            amm_program.key.as_ref(),
-            market.key.as_ref(),
-            b"amm_associated_seed"],
-        bump,
-        seeds::program = amm_program.key
-    )]
-    pub amm: AccountInfo<'info>,
-    /// CHECK: Safe
-    #[account(
-        seeds = [b"amm authority"],
-        bump,
-        seeds::program = amm_program.key
-    )]
-    pub amm_authority: AccountInfo<'info>,
-    /// CHECK: Safe
-    #[account(
-        mut,
-        seeds = [
-            amm_program.key.as_ref(),
-            market.key.as_ref(),
-            b"open_order_associated_seed"],
-        bump,
-        seeds::program = amm_program.key
-    )]
-    pub amm_open_orders: AccountInfo<'info>,
-    /// CHECK: Safe
+    //  registry page of the curve, only for registered curves
     #[account(
         mut,
         seeds = [
-            amm_program.key.as_ref(),
-            market.key.as_ref(),
-            b"lp_mint_associated_seed"
+            RegistryPage::SEED_PREFIX.as_bytes(),
+            &RegistryPage::page_of(bonding_curve.registry_index).to_le_bytes(),
         ],
-        bump,
-        seeds::program = amm_program.key
+        bump
     )]
-    pub lp_mint: AccountInfo<'info>,
+    registry_page: Option<Box<Account<'info, RegistryPage>>>,
 
-    #[account(mut)]
-    pub coin_mint: Box<Account<'info, Mint>>,
-    /// CHECK: Safe. Pc mint account
-    #[account(mut)]
-    pub pc_mint: Box<Account<'info, Mint>>,
-    /// CHECK: Safe
-    #[account(
-        mut,
-        seeds = [
-            amm_program.key.as_ref(),
-            market.key.as_ref(),
-            b"coin_vault_associated_seed"
-        ],
-        bump,
-        seeds::program = amm_program.key
-    )]
-    pub coin_vault: AccountInfo<'info>,
-    /// CHECK: Safe
-    #[account(
-        mut,
-        seeds = [
-            amm_program.key.as_ref(),
-            market.key.as_ref(),
-            b"pc_vault_associated_seed"
-        ],
-        bump,
-        seeds::program = amm_program.key
-    )]
-    pub pc_vault: AccountInfo<'info>,
-    /// CHECK: Safe
+    //  holds the lp tokens when the config locks them, only for `LpPolicy::Lock`
     #[account(
-        mut,
-        seeds = [
-            amm_program.key.as_ref(),
-            market.key.as_ref(),
-            b"target_associated_seed"
-        ],
-        bump,
-        seeds::program = amm_program.key
+        init,
+        payer = payer,
+        space = 8 + LpLock::INIT_SPACE,
+        seeds = [LpLock::SEED_PREFIX.as_bytes(), coin_mint.key().as_ref()],
+        bump
     )]
-    pub target_orders: AccountInfo<'info>,
-    /// CHECK: Safe
+    lp_lock: Option<Box<Account<'info, LpLock>>>,
+
+    /// CHECK: ata of lp_lock for the lp mint, checked and created after the pool
+    #[account(mut)]
+    lp_lock_token_account: Option<AccountInfo<'info>>,
+
+    /// CHECK
     #[account(
         mut,
-        seeds = [b"amm_config_account_seed"],
+        seeds = [GLOBAL.as_bytes()],
         bump,
-        seeds::program = amm_program.key
     )]
-    pub amm_config: AccountInfo<'info>,
+    pub global_vault: AccountInfo<'info>,
 
-    /// CHECK: Safe. OpenBook program.
-    pub market_program: AccountInfo<'info>,
-    /// CHECK: Safe. OpenBook market. OpenBook program is the owner.
+    #[account(mint::token_program = token_program)]
+    pub coin_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
-       mut
+        address = bonding_curve.pc_mint() @PumpfunError::InvalidQuoteMint,
+        mint::token_program = quote_token_program,
     )]
-    pub market: AccountInfo<'info>,
-    /// CHECK: Safe. The user wallet create the pool
+    pub pc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    //  keeper running the migration, receives the crank reward
     #[account(mut)]
-    pub user_wallet: Signer<'info>,
+    pub payer: Signer<'info>,
 
     /// CHECK: verified in transfer instruction
     #[account(
         mut,
         seeds = [
             global_vault.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
+            token_program.key().as_ref(),
             coin_mint.key().as_ref(),
         ],
         bump,
@@ -279,27 +87,64 @@ pub struct Migrate<'info> {
     )]
     global_token_account: AccountInfo<'info>,
 
+    //  wsol or quote token account of global_vault, funded by `transfer_fee`
     #[account(
         mut,
-        seeds = [
-            admin.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
-            coin_mint.key().as_ref(),
-        ],
-        bump,
-        seeds::program = anchor_spl::associated_token::ID
+        associated_token::mint = pc_mint,
+        associated_token::authority = global_vault,
+        associated_token::token_program = quote_token_program
     )]
-    admin_token_account: AccountInfo<'info>,
+    global_wsol_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: Safe. The user pc token
-    #[account(
-        mut,
-    )]
-    pub user_token_pc: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub sysvar_rent: Sysvar<'info, Rent>,
+}
 
-    /// CHECK: Safe. The user lp token
-    #[account(
-        mut,
-    )]
-    pub user_token_lp: AccountInfo<'info>,
+impl<'info> Migrate<'info> {
+    pub fn process(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        global_vault_bump: u8,
+        lp_lock_bump: Option<u8>,
+    ) -> Result<()> {
+        let bonding_curve = &self.bonding_curve;
+
+        require!(bonding_curve.is_fee_settled, PumpfunError::FeeNotSettled);
+        require!(!bonding_curve.is_migrated, PumpfunError::AlreadyMigrated);
+
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        let pool = PoolAccounts {
+            global_vault: self.global_vault.clone(),
+            coin_mint: self.coin_mint.to_account_info(),
+            pc_mint: self.pc_mint.to_account_info(),
+            coin_account: self.global_token_account.clone(),
+            pc_account: self.global_wsol_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            quote_token_program: self.quote_token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.sysvar_rent.to_account_info(),
+        };
+        let caller = MigrationCaller {
+            payer: self.payer.to_account_info(),
+            lp_lock: self.lp_lock.as_deref_mut(),
+            lp_lock_token_account: self.lp_lock_token_account.clone(),
+            lp_lock_bump,
+        };
+
+        migrate_curve(
+            &self.global_config,
+            &mut self.bonding_curve,
+            self.registry_page.as_deref_mut().map(|page| &mut **page),
+            &pool,
+            caller,
+            &self.system_program,
+            remaining_accounts,
+            signer_seeds,
+        )
+    }
 }
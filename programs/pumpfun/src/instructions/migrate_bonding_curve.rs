@@ -0,0 +1,73 @@
+use crate::{
+    constants::BONDING_CURVE,
+    errors::*,
+    state::{is_v0_layout, BondingCurve, BondingCurveV0},
+    utils::resize_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct MigrateBondingCurve<'info> {
+    //  Anyone can pay for the migration, the layout upgrade is deterministic
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: layout is detected and upgraded inside the instruction
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    bonding_curve: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateBondingCurve<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let bonding_curve = {
+            let data = self.bonding_curve.try_borrow_data()?;
+            if data.len() < 8 || data[0..8] != BondingCurve::DISCRIMINATOR {
+                return err!(PumpfunError::UnsupportedLayoutVersion);
+            }
+
+            if is_v0_layout(&data, &self.token_mint.key()) {
+                BondingCurve::from(BondingCurveV0::deserialize(&mut &data[8..])?)
+            } else {
+                match data[8] {
+                    1 => BondingCurve::from_v1(&data[8..])?,
+                    BondingCurve::VERSION => {
+                        msg!(
+                            "bonding curve is already at version {}",
+                            BondingCurve::VERSION
+                        );
+                        return Ok(());
+                    }
+                    _ => return err!(PumpfunError::UnsupportedLayoutVersion),
+                }
+            }
+        };
+
+        let serialized_curve = [
+            &BondingCurve::DISCRIMINATOR,
+            bonding_curve.try_to_vec()?.as_slice(),
+        ]
+        .concat();
+        resize_account(
+            &self.bonding_curve,
+            &self.payer,
+            &self.system_program,
+            8 + BondingCurve::INIT_SPACE,
+        )?;
+
+        (self.bonding_curve.try_borrow_mut_data()?[..serialized_curve.len()])
+            .copy_from_slice(serialized_curve.as_slice());
+
+        msg!("bonding curve migrated to version {}", BondingCurve::VERSION);
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    constants::CONFIG,
+    errors::*,
+    state::{is_v0_layout, Config, ConfigV0},
+    utils::resize_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    //  Current admin, checked against the stored layout
+    #[account(mut)]
+    admin: Signer<'info>,
+
+    /// CHECK: layout is detected and upgraded inside the instruction
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    config: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateConfig<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let config = {
+            let data = self.config.try_borrow_data()?;
+            if data.len() < 8 || data[0..8] != Config::DISCRIMINATOR {
+                return err!(PumpfunError::IncorrectConfigAccount);
+            }
+
+            if is_v0_layout(&data, &self.admin.key()) {
+                Config::from(ConfigV0::deserialize(&mut &data[8..])?)
+            } else {
+                let mut config = Config::deserialize(&mut &data[8..])?;
+                if config.authority != self.admin.key() {
+                    return err!(PumpfunError::IncorrectAuthority);
+                }
+                match config.version {
+                    //  migration costs were carved from the padding, 0 meant the defaults
+                    1 => {
+                        if config.migration_cost_lamports == 0 {
+                            config.migration_cost_lamports =
+                                Config::DEFAULT_MIGRATION_COST_LAMPORTS;
+                            config.market_cost_lamports = Config::DEFAULT_MARKET_COST_LAMPORTS;
+                        }
+                        config.version = Config::VERSION;
+                        config
+                    }
+                    Config::VERSION => {
+                        msg!("config is already at version {}", Config::VERSION);
+                        return Ok(());
+                    }
+                    _ => return err!(PumpfunError::UnsupportedLayoutVersion),
+                }
+            }
+        };
+
+        let serialized_config =
+            [&Config::DISCRIMINATOR, config.try_to_vec()?.as_slice()].concat();
+        resize_account(
+            &self.config,
+            &self.admin,
+            &self.system_program,
+            serialized_config.len(),
+        )?;
+
+        (self.config.try_borrow_mut_data()?[..serialized_config.len()])
+            .copy_from_slice(serialized_config.as_slice());
+
+        msg!("config migrated to version {}", Config::VERSION);
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{
+    errors::*,
+    state::{is_v0_layout, Whitelist, WhitelistV0},
+    utils::resize_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct MigrateWhitelist<'info> {
+    //  Anyone can pay for the migration, the layout upgrade is deterministic
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: layout is detected and upgraded inside the instruction
+    #[account(
+        mut,
+        seeds = [Whitelist::SEED_PREFIX.as_bytes(), creator.as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    whitelist: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateWhitelist<'info> {
+    pub fn process(&mut self, creator: Pubkey) -> Result<()> {
+        let whitelist = {
+            let data = self.whitelist.try_borrow_data()?;
+            if data.len() < 8 || data[0..8] != Whitelist::DISCRIMINATOR {
+                return err!(PumpfunError::UnsupportedLayoutVersion);
+            }
+
+            if !is_v0_layout(&data, &creator) {
+                if data[8] != Whitelist::VERSION {
+                    return err!(PumpfunError::UnsupportedLayoutVersion);
+                }
+                msg!("whitelist is already at version {}", Whitelist::VERSION);
+                return Ok(());
+            }
+
+            Whitelist::from(WhitelistV0::deserialize(&mut &data[8..])?)
+        };
+
+        let serialized_whitelist = [
+            &Whitelist::DISCRIMINATOR,
+            whitelist.try_to_vec()?.as_slice(),
+        ]
+        .concat();
+        resize_account(
+            &self.whitelist,
+            &self.payer,
+            &self.system_program,
+            8 + Whitelist::INIT_SPACE,
+        )?;
+
+        (self.whitelist.try_borrow_mut_data()?[..serialized_whitelist.len()])
+            .copy_from_slice(serialized_whitelist.as_slice());
+
+        msg!("whitelist migrated to version {}", Whitelist::VERSION);
+        Ok(())
+    }
+}
@@ -1,11 +1,63 @@
 pub mod configure;
 pub use configure::*;
+pub mod create_collection;
+pub use create_collection::*;
 pub mod nominate_authority;
 pub use nominate_authority::*;
 pub mod accept_authority;
 pub use accept_authority::*;
+pub mod cancel_authority;
+pub use cancel_authority::*;
+pub mod set_fees;
+pub use set_fees::*;
+pub mod set_curve_limit;
+pub use set_curve_limit::*;
+pub mod set_launch_limits;
+pub use set_launch_limits::*;
+pub mod set_quote_mint;
+pub use set_quote_mint::*;
+pub mod set_vesting_schedule;
+pub use set_vesting_schedule::*;
+pub mod set_allocation_limits;
+pub use set_allocation_limits::*;
+pub mod set_airdrop_claim_window;
+pub use set_airdrop_claim_window::*;
+pub mod set_metadata_rules;
+pub use set_metadata_rules::*;
+pub mod set_launch_rate_limits;
+pub use set_launch_rate_limits::*;
+pub mod set_launch_fee;
+pub use set_launch_fee::*;
+pub mod set_graduation_rewards;
+pub use set_graduation_rewards::*;
+pub mod set_crank_reward;
+pub use set_crank_reward::*;
+pub mod set_migration_costs;
+pub use set_migration_costs::*;
+pub mod set_migration_target;
+pub use set_migration_target::*;
+pub mod set_lp_policy;
+pub use set_lp_policy::*;
+pub mod migrate_config;
+pub use migrate_config::*;
+pub mod migrate_bonding_curve;
+pub use migrate_bonding_curve::*;
+pub mod migrate_whitelist;
+pub use migrate_whitelist::*;
 pub mod launch;
 pub use launch::*;
+pub mod list_existing;
+pub use list_existing::*;
+pub mod claim_vested;
+pub use claim_vested::*;
+pub mod unlock_lp;
+pub use unlock_lp::*;
+pub mod claim_airdrop;
+pub use claim_airdrop::*;
+pub mod clawback_airdrop;
+pub use clawback_airdrop::*;
+pub mod update_metadata;
+pub use update_metadata::*;
 pub mod simulate_swap;
 pub mod swap;
 pub use simulate_swap::*;
@@ -18,5 +70,15 @@ pub mod add_wl;
 pub use add_wl::*;
 pub mod remove_wl;
 pub use remove_wl::*;
+pub mod set_creator_merkle_root;
+pub use set_creator_merkle_root::*;
+pub mod set_compliance_authority;
+pub use set_compliance_authority::*;
+pub mod block_wallet;
+pub use block_wallet::*;
+pub mod unblock_wallet;
+pub use unblock_wallet::*;
 pub mod migrate;
 pub use migrate::*;
+pub mod graduate;
+pub use graduate::*;
@@ -1,5 +1,7 @@
 use constants::CONFIG;
 use errors::PumpfunError;
+use events::AuthorityNominated;
+use state::AuthorityRole;
 
 use crate::*;
 
@@ -22,8 +24,36 @@ pub struct NominateAuthority<'info> {
 }
 
 impl NominateAuthority<'_> {
-    pub fn process(&mut self, new_admin: Pubkey) -> Result<()> {
-        self.global_config.pending_authority = new_admin;
+    pub fn process(
+        &mut self,
+        role: AuthorityRole,
+        new_admin: Pubkey,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        require!(
+            new_admin != Pubkey::default(),
+            PumpfunError::IncorrectAuthority
+        );
+
+        //  0 means the nomination never expires
+        let expiry = expiry.unwrap_or(0);
+        if expiry != 0 {
+            require!(
+                expiry > Clock::get()?.unix_timestamp,
+                PumpfunError::NominationExpired
+            );
+        }
+
+        self.global_config
+            .set_pending_nomination(role, new_admin, expiry);
+
+        emit!(AuthorityNominated {
+            role,
+            current: self.global_config.role_holder(role),
+            nominee: new_admin,
+            expiry,
+        });
+
         Ok(())
     }
 }
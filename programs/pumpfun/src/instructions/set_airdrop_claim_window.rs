@@ -0,0 +1,29 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetAirdropClaimWindow<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores airdrop claim window of new launches
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetAirdropClaimWindow<'_> {
+    pub fn process(&mut self, airdrop_claim_window: i64) -> Result<()> {
+        self.global_config.airdrop_claim_window = airdrop_claim_window;
+
+        self.global_config.validate()
+    }
+}
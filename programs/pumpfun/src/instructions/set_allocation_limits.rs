@@ -0,0 +1,38 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetAllocationLimits<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores allocation bounds of new launches
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetAllocationLimits<'_> {
+    pub fn process(
+        &mut self,
+        min_curve_bps: u16,
+        max_creator_bps: u16,
+        max_team_bps: u16,
+        max_airdrop_bps: u16,
+    ) -> Result<()> {
+        self.global_config.min_curve_bps = min_curve_bps;
+        self.global_config.max_creator_bps = max_creator_bps;
+        self.global_config.max_team_bps = max_team_bps;
+        self.global_config.max_airdrop_bps = max_airdrop_bps;
+
+        self.global_config.validate()
+    }
+}
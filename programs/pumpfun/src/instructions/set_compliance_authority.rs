@@ -0,0 +1,28 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetComplianceAuthority<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores compliance authority address
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetComplianceAuthority<'_> {
+    pub fn process(&mut self, compliance_authority: Pubkey) -> Result<()> {
+        self.global_config.compliance_authority = compliance_authority;
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetCrankReward<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores migration crank reward
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetCrankReward<'_> {
+    pub fn process(&mut self, migration_crank_reward_lamports: u64) -> Result<()> {
+        self.global_config.migration_crank_reward_lamports = migration_crank_reward_lamports;
+
+        self.global_config.validate()
+    }
+}
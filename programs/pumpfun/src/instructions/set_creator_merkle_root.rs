@@ -0,0 +1,27 @@
+use crate::*;
+
+use constants::CONFIG;
+use errors::PumpfunError;
+
+#[derive(Accounts)]
+pub struct SetCreatorMerkleRoot<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        constraint = admin.key() == global_config.global_authority.key() @ PumpfunError::InvalidGlobalAuthority
+    )]
+    pub admin: Signer<'info>,
+}
+
+impl SetCreatorMerkleRoot<'_> {
+    pub fn process(&mut self, creator_merkle_root: [u8; 32], whitelist_enabled: bool) -> Result<()> {
+        self.global_config.creator_merkle_root = creator_merkle_root;
+        self.global_config.whitelist_enabled = whitelist_enabled;
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetCurveLimit<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores curve limit
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetCurveLimit<'_> {
+    pub fn process(&mut self, curve_limit: u64) -> Result<()> {
+        self.global_config.curve_limit = curve_limit;
+
+        self.global_config.validate()
+    }
+}
@@ -0,0 +1,36 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetFees<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores fee settings
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetFees<'_> {
+    pub fn process(
+        &mut self,
+        platform_buy_fee: f64,
+        platform_sell_fee: f64,
+        platform_migration_fee: f64,
+    ) -> Result<()> {
+        self.global_config.platform_buy_fee = platform_buy_fee;
+        self.global_config.platform_sell_fee = platform_sell_fee;
+        self.global_config.platform_migration_fee = platform_migration_fee;
+
+        self.global_config.validate()
+    }
+}
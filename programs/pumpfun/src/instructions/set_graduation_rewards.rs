@@ -0,0 +1,34 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetGraduationRewards<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores graduation reward shares
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetGraduationRewards<'_> {
+    pub fn process(
+        &mut self,
+        creator_graduation_reward_bps: u16,
+        completer_graduation_reward_bps: u16,
+    ) -> Result<()> {
+        self.global_config.creator_graduation_reward_bps = creator_graduation_reward_bps;
+        self.global_config.completer_graduation_reward_bps = completer_graduation_reward_bps;
+
+        self.global_config.validate()
+    }
+}
@@ -0,0 +1,34 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetLaunchFee<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores launch fee
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetLaunchFee<'_> {
+    pub fn process(
+        &mut self,
+        launch_fee_lamports: u64,
+        refund_launch_fee_on_graduation: bool,
+    ) -> Result<()> {
+        self.global_config.launch_fee_lamports = launch_fee_lamports;
+        self.global_config.refund_launch_fee_on_graduation = refund_launch_fee_on_graduation;
+
+        self.global_config.validate()
+    }
+}
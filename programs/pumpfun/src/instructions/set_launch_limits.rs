@@ -0,0 +1,50 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use state::AmountConfig;
+use utils::resize_account;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetLaunchLimits<'info> {
+    // Current admin
+    #[account(
+        mut,
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores launch limits
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl SetLaunchLimits<'_> {
+    pub fn process(
+        &mut self,
+        lamport_amount_config: AmountConfig<u64>,
+        token_supply_config: AmountConfig<u64>,
+        token_decimals_config: AmountConfig<u8>,
+    ) -> Result<()> {
+        self.global_config.lamport_amount_config = lamport_amount_config;
+        self.global_config.token_supply_config = token_supply_config;
+        self.global_config.token_decimals_config = token_decimals_config;
+
+        self.global_config.validate()?;
+
+        //  enum options can grow the config, so realloc before it is written back
+        let config_len = 8 + self.global_config.try_to_vec()?.len();
+        resize_account(
+            &self.global_config.to_account_info(),
+            &self.admin,
+            &self.system_program,
+            config_len,
+        )
+    }
+}
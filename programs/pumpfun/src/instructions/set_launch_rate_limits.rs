@@ -0,0 +1,36 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetLaunchRateLimits<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores per creator launch limits
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetLaunchRateLimits<'_> {
+    pub fn process(
+        &mut self,
+        launch_cooldown: i64,
+        launch_window: i64,
+        max_launches_per_window: u32,
+    ) -> Result<()> {
+        self.global_config.launch_cooldown = launch_cooldown;
+        self.global_config.launch_window = launch_window;
+        self.global_config.max_launches_per_window = max_launches_per_window;
+
+        self.global_config.validate()
+    }
+}
@@ -0,0 +1,31 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use state::LpPolicy;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetLpPolicy<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores lp policy of new pools
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetLpPolicy<'_> {
+    pub fn process(&mut self, lp_policy: LpPolicy, lp_lock_duration: i64) -> Result<()> {
+        self.global_config.lp_policy = lp_policy;
+        self.global_config.lp_lock_duration = lp_lock_duration;
+
+        self.global_config.validate()
+    }
+}
@@ -0,0 +1,43 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use state::MetadataRules;
+use utils::resize_account;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetMetadataRules<'info> {
+    // Current admin
+    #[account(
+        mut,
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores metadata rules
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl SetMetadataRules<'_> {
+    pub fn process(&mut self, metadata_rules: MetadataRules) -> Result<()> {
+        self.global_config.metadata_rules = metadata_rules;
+
+        self.global_config.validate()?;
+
+        //  uri prefixes can grow the config, so realloc before it is written back
+        let config_len = 8 + self.global_config.try_to_vec()?.len();
+        resize_account(
+            &self.global_config.to_account_info(),
+            &self.admin,
+            &self.system_program,
+            config_len,
+        )
+    }
+}
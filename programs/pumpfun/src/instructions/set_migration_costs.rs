@@ -0,0 +1,34 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetMigrationCosts<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores pool and market creation costs
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetMigrationCosts<'_> {
+    pub fn process(
+        &mut self,
+        migration_cost_lamports: u64,
+        market_cost_lamports: u64,
+    ) -> Result<()> {
+        self.global_config.migration_cost_lamports = migration_cost_lamports;
+        self.global_config.market_cost_lamports = market_cost_lamports;
+
+        self.global_config.validate()
+    }
+}
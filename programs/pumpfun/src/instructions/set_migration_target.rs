@@ -0,0 +1,30 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+use state::MigrationTarget;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetMigrationTarget<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores default migration target
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetMigrationTarget<'_> {
+    pub fn process(&mut self, migration_target: MigrationTarget) -> Result<()> {
+        self.global_config.migration_target = migration_target;
+
+        self.global_config.validate()
+    }
+}
@@ -0,0 +1,61 @@
+use constants::{CONFIG, QUOTE_CONFIG};
+use errors::PumpfunError;
+use state::QuoteConfig;
+
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetQuoteMint<'info> {
+    // Current admin
+    #[account(
+        mut,
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(mint::token_program = quote_token_program)]
+    quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    //  Stores approval and limits of the quote mint
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + QuoteConfig::INIT_SPACE,
+        seeds = [QUOTE_CONFIG.as_bytes(), quote_mint.key().as_ref()],
+        bump,
+    )]
+    quote_config: Box<Account<'info, QuoteConfig>>,
+
+    quote_token_program: Interface<'info, TokenInterface>,
+
+    system_program: Program<'info, System>,
+}
+
+impl SetQuoteMint<'_> {
+    pub fn process(
+        &mut self,
+        enabled: bool,
+        curve_limit: u64,
+        min_init_reserve: u64,
+        max_init_reserve: u64,
+    ) -> Result<()> {
+        let quote_config = &mut self.quote_config;
+
+        quote_config.version = QuoteConfig::VERSION;
+        quote_config.mint = self.quote_mint.key();
+        quote_config.enabled = enabled;
+        quote_config.curve_limit = curve_limit;
+        quote_config.min_init_reserve = min_init_reserve;
+        quote_config.max_init_reserve = max_init_reserve;
+
+        quote_config.validate()
+    }
+}
@@ -0,0 +1,30 @@
+use constants::CONFIG;
+use errors::PumpfunError;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetVestingSchedule<'info> {
+    // Current admin
+    #[account(
+        constraint = global_config.authority == *admin.key @PumpfunError::IncorrectAuthority
+    )]
+    pub admin: Signer<'info>,
+
+    //  Stores vesting schedule of new launches
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+}
+
+impl SetVestingSchedule<'_> {
+    pub fn process(&mut self, vesting_cliff: i64, vesting_duration: i64) -> Result<()> {
+        self.global_config.vesting_cliff = vesting_cliff;
+        self.global_config.vesting_duration = vesting_duration;
+
+        self.global_config.validate()
+    }
+}
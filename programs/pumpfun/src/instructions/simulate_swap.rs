@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_spl::token_interface::Mint;
 use crate::{
     constants::{BONDING_CURVE, CONFIG}, state::{BondingCurve, Config, BondingCurveAccount}
 };
@@ -18,7 +18,7 @@ pub struct SimulateSwap<'info> {
     )]
     bonding_curve: Account<'info, BondingCurve>,
 
-    pub token_mint: Box<Account<'info, Mint>>,
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 }
 
 impl <'info> SimulateSwap<'info> {
@@ -1,13 +1,14 @@
 use anchor_lang::{system_program, prelude::*};
 use anchor_spl::{
     associated_token::{self, AssociatedToken},
-    token::{self, Mint, Token},
+    token_interface::{Mint, TokenInterface},
 };
 use crate::{
-    constants::{BONDING_CURVE, CONFIG, GLOBAL}, 
+    constants::{BONDING_CURVE, CONFIG, GLOBAL, QUOTE_CONFIG}, 
     errors::PumpfunError, 
     events::SwapEvent,
-    state::{BondingCurve, Config, BondingCurveAccount}
+    state::{Blocklist, BondingCurve, Config, CreatorProfile, BondingCurveAccount, LaunchStatus, QuoteAccounts, QuoteConfig, RegistryPage},
+    utils::{check_ata_address, create_ata_if_needed},
 };
 
 #[derive(Accounts)]
@@ -31,7 +32,7 @@ pub struct Swap<'info> {
         mut,
         seeds = [
             team_wallet.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
+            token_program.key().as_ref(),
             token_mint.key().as_ref(),
         ],
         bump,
@@ -47,6 +48,32 @@ pub struct Swap<'info> {
     )]
     bonding_curve: Account<'info, BondingCurve>,
 
+    //  registry page of the curve, only for registered curves
+    #[account(
+        mut,
+        seeds = [
+            RegistryPage::SEED_PREFIX.as_bytes(),
+            &RegistryPage::page_of(bonding_curve.registry_index).to_le_bytes(),
+        ],
+        bump
+    )]
+    registry_page: Option<Box<Account<'info, RegistryPage>>>,
+
+    /// CHECK: profile pda of the curve creator, counts graduations if it exists
+    #[account(
+        mut,
+        seeds = [CreatorProfile::SEED_PREFIX.as_bytes(), bonding_curve.creator.as_ref()],
+        bump,
+    )]
+    creator_profile: UncheckedAccount<'info>,
+
+    /// CHECK: creator of the curve, receives the launch fee refund on graduation
+    #[account(
+        mut,
+        address = bonding_curve.creator @PumpfunError::IncorrectAuthority,
+    )]
+    creator: UncheckedAccount<'info>,
+
     /// CHECK: global vault pda which stores SOL
     #[account(
         mut,
@@ -55,14 +82,15 @@ pub struct Swap<'info> {
     )]
     pub global_vault: AccountInfo<'info>,
 
-    pub token_mint: Box<Account<'info, Mint>>,
+    #[account(mint::token_program = token_program)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// CHECK: ata of global vault
     #[account(
         mut,
         seeds = [
             global_vault.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
+            token_program.key().as_ref(),
             token_mint.key().as_ref(),
         ],
         bump,
@@ -75,7 +103,7 @@ pub struct Swap<'info> {
         mut,
         seeds = [
             user.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
+            token_program.key().as_ref(),
             token_mint.key().as_ref(),
         ],
         bump,
@@ -86,18 +114,53 @@ pub struct Swap<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    /// CHECK: blocklist pda of user, only exists if the user is blocked
+    #[account(
+        seeds = [Blocklist::SEED_PREFIX.as_bytes(), user.key().as_ref()],
+        bump,
+    )]
+    user_blocklist: UncheckedAccount<'info>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
-    #[account(address = token::ID)]
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     #[account(address = associated_token::ID)]
     pub associated_token_program: Program<'info, AssociatedToken>,
+
+    //  quote accounts, only for curves priced in an spl quote mint
+    #[account(
+        seeds = [QUOTE_CONFIG.as_bytes(), bonding_curve.quote_mint.as_ref()],
+        bump,
+    )]
+    quote_config: Option<Box<Account<'info, QuoteConfig>>>,
+
+    #[account(
+        address = bonding_curve.quote_mint @PumpfunError::InvalidQuoteMint,
+        mint::token_program = quote_token_program,
+    )]
+    quote_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// CHECK: quote ata of user, checked in instruction
+    #[account(mut)]
+    user_quote_ata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: quote ata of global vault, checked in instruction
+    #[account(mut)]
+    global_quote_ata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: quote ata of team wallet, checked in instruction
+    #[account(mut)]
+    team_wallet_quote_ata: Option<UncheckedAccount<'info>>,
+
+    quote_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 impl<'info> Swap<'info> { 
 pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u64,global_vault_bump:u8) -> Result<u64> {
+    Blocklist::check_not_blocked(&self.user_blocklist)?;
+
     let bonding_curve = &mut self.bonding_curve;
 
     //  check curve is not completed
@@ -150,9 +213,59 @@ pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u6
         &[global_vault_bump],
     ]];
 
-    
+    let curve_limit = bonding_curve.curve_limit(&self.global_config, self.quote_config.as_deref().map(|c| &**c))?;
+
+    //  spl quote curves move quote tokens instead of lamports
+    let quote = if bonding_curve.is_sol_quote() {
+        None
+    } else {
+        let (
+            Some(quote_mint),
+            Some(user_quote_ata),
+            Some(global_quote_ata),
+            Some(team_wallet_quote_ata),
+            Some(quote_token_program),
+        ) = (
+            self.quote_mint.as_deref(),
+            self.user_quote_ata.as_ref(),
+            self.global_quote_ata.as_ref(),
+            self.team_wallet_quote_ata.as_ref(),
+            self.quote_token_program.as_ref(),
+        ) else {
+            return err!(PumpfunError::InvalidQuoteAccount);
+        };
+
+        check_ata_address(user_quote_ata, &self.user.key(), &quote_mint.key(), &quote_token_program.key())?;
+        check_ata_address(global_quote_ata, &source.key(), &quote_mint.key(), &quote_token_program.key())?;
+        check_ata_address(team_wallet_quote_ata, &team_wallet.key(), &quote_mint.key(), &quote_token_program.key())?;
+
+        for (ata, authority) in [
+            (user_quote_ata, self.user.to_account_info()),
+            (team_wallet_quote_ata, team_wallet.to_account_info()),
+        ] {
+            create_ata_if_needed(
+                self.user.to_account_info(),
+                ata.to_account_info(),
+                authority,
+                quote_mint.to_account_info(),
+                quote_token_program.to_account_info(),
+                self.system_program.to_account_info(),
+                self.associated_token_program.to_account_info(),
+            )?;
+        }
+
+        Some(QuoteAccounts {
+            mint: quote_mint,
+            user_ata: user_quote_ata.to_account_info(),
+            vault_ata: global_quote_ata.to_account_info(),
+            team_wallet_ata: team_wallet_quote_ata.to_account_info(),
+            token_program: quote_token_program,
+        })
+    };
+
     let amount_out = bonding_curve.swap(
         &*self.global_config,
+        curve_limit,
         token.as_ref(),
         &mut self.global_ata,
         user_ata,
@@ -168,8 +281,20 @@ pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u6
 
         &self.token_program,
         &self.system_program,
+
+        quote,
     )?;
 
+    if bonding_curve.is_completed {
+        RegistryPage::set_status(
+            self.registry_page.as_deref_mut().map(|page| &mut **page),
+            bonding_curve,
+            LaunchStatus::Completed,
+        )?;
+        CreatorProfile::record_graduation(&self.creator_profile)?;
+        bonding_curve.release_launch_fee(&self.team_wallet, &self.creator)?;
+    }
+
     emit!(
         SwapEvent {
             user: self.user.key(),
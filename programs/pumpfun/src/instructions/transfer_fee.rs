@@ -3,16 +3,21 @@ use std::ops::{Div, Mul};
 use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    metadata::{self, Metadata},
+    token_2022,
+    token_2022_extensions::spl_pod::optional_keys::OptionalNonZeroPubkey,
+    token_interface::{self, TokenInterface},
 };
 use spl_token::instruction::sync_native;
 
 use crate::{
-    constants::{BONDING_CURVE, CONFIG, GLOBAL},
+    constants::{BONDING_CURVE, CONFIG, GLOBAL, METADATA},
     errors::PumpfunError,
+    events::{GraduationRewardsPaid, MetadataLockedEvent, MigrationCostBreakdown},
     state::{BondingCurve, Config},
     utils::{
-        convert_from_float, convert_to_float, sol_transfer_with_signer, token_transfer_with_signer,
+        convert_from_float, convert_to_float, create_ata_if_needed, sol_transfer_from_user,
+        sol_transfer_with_signer, token_transfer_with_signer,
     },
 };
 
@@ -29,14 +34,14 @@ pub struct TransferFee<'info> {
         seeds = [CONFIG.as_bytes()],
         bump,
     )]
-    global_config: Box<Account<'info, Config>>,
+    pub(crate) global_config: Box<Account<'info, Config>>,
 
     #[account(
         mut,
         seeds = [BONDING_CURVE.as_bytes(), &coin_mint.key().to_bytes()],
         bump
     )]
-    bonding_curve: Box<Account<'info, BondingCurve>>,
+    pub(crate) bonding_curve: Box<Account<'info, BondingCurve>>,
 
     /// CHECK
     #[account(
@@ -44,41 +49,45 @@ pub struct TransferFee<'info> {
         seeds = [GLOBAL.as_bytes()],
         bump,
     )]
-    global_vault: UncheckedAccount<'info>,
+    pub(crate) global_vault: UncheckedAccount<'info>,
 
-    token_program: Program<'info, Token>,
-    associated_token_program: Program<'info, AssociatedToken>,
-    system_program: Program<'info, System>,
+    pub(crate) token_program: Interface<'info, TokenInterface>,
+    //  spl token for wsol, or the program of the quote mint
+    pub(crate) quote_token_program: Interface<'info, TokenInterface>,
+    pub(crate) associated_token_program: Program<'info, AssociatedToken>,
+    pub(crate) system_program: Program<'info, System>,
 
-    coin_mint: Box<Account<'info, Mint>>,
+    #[account(mint::token_program = token_program)]
+    pub(crate) coin_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
     #[account(
-        address = spl_token::native_mint::ID
+        address = bonding_curve.pc_mint() @PumpfunError::InvalidQuoteMint,
+        mint::token_program = quote_token_program,
     )]
-    pc_mint: Box<Account<'info, Mint>>,
+    pub(crate) pc_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
     /// CHECK: Safe. The user wallet create the pool
     #[account(mut)]
-    payer: Signer<'info>,
+    pub(crate) payer: Signer<'info>,
 
     /// CHECK: verified in transfer instruction
     #[account(
         mut,
         seeds = [
             global_vault.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
+            token_program.key().as_ref(),
             coin_mint.key().as_ref(),
         ],
         bump,
         seeds::program = anchor_spl::associated_token::ID
     )]
-    global_token_account: UncheckedAccount<'info>,
+    pub(crate) global_token_account: UncheckedAccount<'info>,
 
     #[account(
         mut,
         seeds = [
             team_wallet.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
+            token_program.key().as_ref(),
             coin_mint.key().as_ref(),
         ],
         bump,
@@ -86,63 +95,250 @@ pub struct TransferFee<'info> {
     )]
     team_ata: UncheckedAccount<'info>,
 
-    /// CHECK: Safe. wsol account of global_vault
+    /// CHECK: Safe. wsol or quote token account of global_vault
     #[account(
         mut,
         associated_token::mint = pc_mint,
-        associated_token::authority = global_vault
+        associated_token::authority = global_vault,
+        associated_token::token_program = quote_token_program
     )]
-    global_wsol_account: Box<Account<'info, TokenAccount>>,
+    pub(crate) global_wsol_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// CHECK: quote ata of team wallet, only for spl quote curves
+    #[account(
+        mut,
+        seeds = [
+            team_wallet.key().as_ref(),
+            quote_token_program.key().as_ref(),
+            pc_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    team_quote_ata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: creator of the curve, receives the creator graduation reward
+    #[account(
+        mut,
+        address = bonding_curve.creator @PumpfunError::IncorrectAuthority
+    )]
+    creator: UncheckedAccount<'info>,
+
+    /// CHECK: wallet which completed the curve, receives the completer graduation reward
+    #[account(
+        mut,
+        address = bonding_curve.completer @PumpfunError::IncorrectAuthority
+    )]
+    completer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: quote ata of the creator, only for spl quote curves with a creator reward
+    #[account(
+        mut,
+        seeds = [
+            creator.key().as_ref(),
+            quote_token_program.key().as_ref(),
+            pc_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    creator_quote_ata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: quote ata of the completer, only for spl quote curves with a completer reward
+    #[account(
+        mut,
+        seeds = [
+            bonding_curve.completer.as_ref(),
+            quote_token_program.key().as_ref(),
+            pc_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    completer_quote_ata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: passed to token metadata program, only used to lock spl token metadata
+    #[account(
+        mut,
+        seeds = [
+            METADATA.as_bytes(),
+            metadata::ID.as_ref(),
+            coin_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = metadata::ID
+    )]
+    token_metadata_account: Option<UncheckedAccount<'info>>,
+
+    mpl_token_metadata_program: Option<Program<'info, Metadata>>,
 }
 
 impl<'info> TransferFee<'info> {
     pub fn process(&mut self, global_vault_bump: u8) -> Result<()> {
-        let global_config = &mut self.global_config;
-        let bonding_curve = &mut self.bonding_curve;
+        let global_config = &self.global_config;
+        let bonding_curve = &self.bonding_curve;
+
+        //  version 1 configs may hold 0 for migration costs that meant the defaults
+        require!(
+            global_config.version == Config::VERSION,
+            PumpfunError::OutdatedAccountLayout
+        );
 
         //  check curve is completed
         require!(
             bonding_curve.is_completed == true,
             PumpfunError::CurveNotCompleted
         );
+        require!(
+            !bonding_curve.is_fee_settled,
+            PumpfunError::FeeAlreadySettled
+        );
 
-        let lamport_on_curve = bonding_curve.reserve_lamport - bonding_curve.init_lamport;
+        let lamport_on_curve = bonding_curve
+            .reserve_lamport
+            .checked_sub(bonding_curve.init_lamport)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        let quote_decimals = bonding_curve.quote_decimals();
 
-        let fee_in_float = convert_to_float(lamport_on_curve, self.coin_mint.decimals)
+        let fee_in_float = convert_to_float(lamport_on_curve, quote_decimals)
             .div(100_f64)
             .mul(global_config.platform_migration_fee);
 
-        let fee_lamport = convert_from_float(fee_in_float, self.coin_mint.decimals);
+        let fee_lamport = convert_from_float(fee_in_float, quote_decimals);
 
-        //  updated this 1 as 0.4
-        //  1 + 0.01715 - pool create fee
+        //  graduation rewards were fixed when the curve completed
+        let rewards = bonding_curve
+            .creator_reward
+            .checked_add(bonding_curve.completer_reward)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
 
-        //  0.3 - market create fee
-        let init_pc_amount = lamport_on_curve - fee_lamport - 1_400_000_000;
+        //  spl quote curves hold no SOL to pay the crank from
+        let crank_reward = if bonding_curve.is_sol_quote() {
+            global_config.migration_crank_reward_lamports
+        } else {
+            0
+        };
+
+        //  pool and market creation costs, held in the vault until `migrate`
+        //  targets without a market only hold back the pool cost
+        //  `Config::validate` keeps the market cost within the migration cost
+        let migration_cost = global_config.migration_cost_lamports;
+        let market_cost = global_config.market_cost_lamports;
+        let pool_cost = migration_cost
+            .checked_sub(market_cost)
+            .ok_or(PumpfunError::InvalidMigrationCost)?;
+        let (migration_cost, market_cost) = if bonding_curve.migration_target.needs_market() {
+            (migration_cost, market_cost)
+        } else {
+            (pool_cost, 0)
+        };
+        //  spl quote curves hold no SOL, the caller deposits the costs instead
+        let held_back_cost = if bonding_curve.is_sol_quote() {
+            migration_cost
+        } else {
+            0
+        };
+
+        let init_pc_amount = lamport_on_curve
+            .checked_sub(fee_lamport)
+            .and_then(|amount| amount.checked_sub(rewards))
+            .and_then(|amount| amount.checked_sub(crank_reward))
+            .and_then(|amount| amount.checked_sub(held_back_cost))
+            .ok_or(PumpfunError::InsufficientProceedsForMigration)?;
 
         let coin_amount = (init_pc_amount as u128 * bonding_curve.reserve_token as u128
             / bonding_curve.reserve_lamport as u128) as u64;
         let fee_token = bonding_curve.reserve_token - coin_amount;
 
         msg!(
-            "Raydium Input:: Token: {:?}  Sol: {:?}",
+            "Raydium Input:: Token: {:?}  Quote: {:?}",
             coin_amount,
             init_pc_amount
         );
         msg!("Fee percent: {:?}", global_config.platform_migration_fee);
-        msg!("Fee:: Token: {:?}  Sol: {:?}", fee_token, fee_lamport);
+        msg!("Fee:: Token: {:?}  Quote: {:?}", fee_token, fee_lamport);
+
+        emit!(MigrationCostBreakdown {
+            mint: self.coin_mint.key(),
+            bonding_curve: bonding_curve.key(),
+            proceeds: lamport_on_curve,
+            platform_fee: fee_lamport,
+            creator_reward: bonding_curve.creator_reward,
+            completer_reward: bonding_curve.completer_reward,
+            crank_reward,
+            market_cost,
+            pool_cost,
+            pool_quote_amount: init_pc_amount,
+            pool_token_amount: coin_amount,
+            fee_token,
+        });
 
         let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
 
-        //  transfer 0.3 SOL to signer for market creation fee
-        sol_transfer_with_signer(
+        let lock_metadata_on_migration = bonding_curve.lock_metadata_on_migration;
+        let is_sol_quote = bonding_curve.is_sol_quote();
+
+        //  pool amounts are held in the vault until `migrate` creates the pool
+        let bonding_curve = &mut self.bonding_curve;
+        bonding_curve.is_fee_settled = true;
+        bonding_curve.pool_pc_amount = init_pc_amount;
+        //  the market cost is paid with the crank reward to the caller of `migrate`,
+        //  whose market the pool is created on
+        bonding_curve.crank_reward = crank_reward
+            .checked_add(market_cost)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        if lock_metadata_on_migration {
+            self.lock_metadata(signer_seeds)?;
+        }
+
+        token_transfer_with_signer(
+            self.global_token_account.to_account_info(),
             self.global_vault.to_account_info(),
-            self.payer.to_account_info(),
-            &self.system_program,
+            self.team_ata.to_account_info(),
+            &self.coin_mint,
+            &self.token_program,
             signer_seeds,
-            300_000_000,
+            fee_token,
         )?;
 
+        if !is_sol_quote {
+            //  the vault pays for the pool, so the caller funds it for this curve
+            sol_transfer_from_user(
+                &self.payer,
+                self.global_vault.to_account_info(),
+                &self.system_program,
+                migration_cost,
+            )?;
+
+            //  quote tokens already sit in the vault ata, only the fee leaves
+            let Some(team_quote_ata) = self.team_quote_ata.as_ref() else {
+                return err!(PumpfunError::InvalidQuoteAccount);
+            };
+            create_ata_if_needed(
+                self.payer.to_account_info(),
+                team_quote_ata.to_account_info(),
+                self.team_wallet.to_account_info(),
+                self.pc_mint.to_account_info(),
+                self.quote_token_program.to_account_info(),
+                self.system_program.to_account_info(),
+                self.associated_token_program.to_account_info(),
+            )?;
+            token_transfer_with_signer(
+                self.global_wsol_account.to_account_info(),
+                self.global_vault.to_account_info(),
+                team_quote_ata.to_account_info(),
+                &self.pc_mint,
+                &self.quote_token_program,
+                signer_seeds,
+                fee_lamport,
+            )?;
+
+            self.pay_graduation_rewards(signer_seeds)?;
+
+            return Ok(());
+        }
+
         //  transfer migration fee to team wallet
         sol_transfer_with_signer(
             self.global_vault.to_account_info(),
@@ -151,14 +347,8 @@ impl<'info> TransferFee<'info> {
             signer_seeds,
             fee_lamport,
         )?;
-        token_transfer_with_signer(
-            self.global_token_account.to_account_info(),
-            self.global_vault.to_account_info(),
-            self.team_ata.to_account_info(),
-            &self.token_program,
-            signer_seeds,
-            fee_token,
-        )?;
+
+        self.pay_graduation_rewards(signer_seeds)?;
 
         //  sync WSOL account of global_acocunt
         sol_transfer_with_signer(
@@ -178,4 +368,134 @@ impl<'info> TransferFee<'info> {
 
         Ok(())
     }
+
+    //  pay the creator and completer rewards out of the curve proceeds
+    fn pay_graduation_rewards(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let bonding_curve = &self.bonding_curve;
+
+        self.pay_reward(
+            self.creator.to_account_info(),
+            self.creator_quote_ata.as_ref(),
+            signer_seeds,
+            bonding_curve.creator_reward,
+        )?;
+
+        let mut completer_reward = 0;
+        if bonding_curve.completer != Pubkey::default() && bonding_curve.completer_reward > 0 {
+            let Some(completer) = self.completer.as_ref() else {
+                return err!(PumpfunError::IncorrectAuthority);
+            };
+            self.pay_reward(
+                completer.to_account_info(),
+                self.completer_quote_ata.as_ref(),
+                signer_seeds,
+                bonding_curve.completer_reward,
+            )?;
+            completer_reward = bonding_curve.completer_reward;
+        }
+
+        emit!(GraduationRewardsPaid {
+            mint: self.coin_mint.key(),
+            bonding_curve: bonding_curve.key(),
+            creator: bonding_curve.creator,
+            creator_reward: bonding_curve.creator_reward,
+            completer: bonding_curve.completer,
+            completer_reward,
+        });
+
+        Ok(())
+    }
+
+    fn pay_reward(
+        &self,
+        recipient: AccountInfo<'info>,
+        recipient_quote_ata: Option<&UncheckedAccount<'info>>,
+        signer_seeds: &[&[&[u8]]],
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        if self.bonding_curve.is_sol_quote() {
+            return sol_transfer_with_signer(
+                self.global_vault.to_account_info(),
+                recipient,
+                &self.system_program,
+                signer_seeds,
+                amount,
+            );
+        }
+
+        let Some(recipient_quote_ata) = recipient_quote_ata else {
+            return err!(PumpfunError::InvalidQuoteAccount);
+        };
+        create_ata_if_needed(
+            self.payer.to_account_info(),
+            recipient_quote_ata.to_account_info(),
+            recipient,
+            self.pc_mint.to_account_info(),
+            self.quote_token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            self.associated_token_program.to_account_info(),
+        )?;
+        token_transfer_with_signer(
+            self.global_wsol_account.to_account_info(),
+            self.global_vault.to_account_info(),
+            recipient_quote_ata.to_account_info(),
+            &self.pc_mint,
+            &self.quote_token_program,
+            signer_seeds,
+            amount,
+        )
+    }
+
+    //  metadata can't be changed after migration
+    fn lock_metadata(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let metadata = if self.token_program.key() == token_2022::ID {
+            token_interface::token_metadata_update_authority(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token_interface::TokenMetadataUpdateAuthority {
+                        token_program_id: self.token_program.to_account_info(),
+                        metadata: self.coin_mint.to_account_info(),
+                        current_authority: self.global_vault.to_account_info(),
+                        new_authority: self.global_vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                OptionalNonZeroPubkey::default(),
+            )?;
+            self.coin_mint.key()
+        } else {
+            let (Some(token_metadata_account), Some(mpl_token_metadata_program)) = (
+                self.token_metadata_account.as_ref(),
+                self.mpl_token_metadata_program.as_ref(),
+            ) else {
+                return err!(PumpfunError::MissingMetadataAccounts);
+            };
+            metadata::update_metadata_accounts_v2(
+                CpiContext::new_with_signer(
+                    mpl_token_metadata_program.to_account_info(),
+                    metadata::UpdateMetadataAccountsV2 {
+                        metadata: token_metadata_account.to_account_info(),
+                        update_authority: self.global_vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                None,
+                None,
+                None,
+                Some(false),
+            )?;
+            token_metadata_account.key()
+        };
+
+        emit!(MetadataLockedEvent {
+            mint: self.coin_mint.key(),
+            metadata,
+        });
+
+        Ok(())
+    }
 }
@@ -0,0 +1,41 @@
+use crate::*;
+
+use constants::CONFIG;
+use errors::PumpfunError;
+use events::BlocklistRemoved;
+use state::Blocklist;
+
+#[derive(Accounts)]
+pub struct UnblockWallet<'info> {
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [Blocklist::SEED_PREFIX.as_bytes(), blocklist.wallet.as_ref()],
+        bump
+    )]
+    pub blocklist: Account<'info, Blocklist>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == global_config.compliance_authority @ PumpfunError::InvalidComplianceAuthority
+    )]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl UnblockWallet<'_> {
+    pub fn process(&mut self) -> Result<()> {
+        emit!(BlocklistRemoved {
+            wallet: self.blocklist.wallet,
+            authority: self.admin.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{errors::PumpfunError, events::LpUnlockEvent, state::LpLock};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::{self, Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct UnlockLp<'info> {
+    #[account(mut)]
+    beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LpLock::SEED_PREFIX.as_bytes(), lp_lock.mint.as_ref()],
+        bump = lp_lock.bump,
+        has_one = beneficiary @PumpfunError::IncorrectAuthority,
+        has_one = lp_mint,
+    )]
+    lp_lock: Box<Account<'info, LpLock>>,
+
+    lp_mint: Box<Account<'info, Mint>>,
+
+    //  ata of lp_lock which holds the locked lp tokens
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = lp_lock,
+    )]
+    lp_lock_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = lp_mint,
+        associated_token::authority = beneficiary,
+    )]
+    beneficiary_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    token_program: Program<'info, Token>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> UnlockLp<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let lp_lock = &mut self.lp_lock;
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(lp_lock.is_unlocked(now), PumpfunError::LpLocked);
+
+        let amount = lp_lock.amount;
+        require!(amount > 0, PumpfunError::NothingToClaim);
+        lp_lock.amount = 0;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            LpLock::SEED_PREFIX.as_bytes(),
+            lp_lock.mint.as_ref(),
+            &[lp_lock.bump],
+        ]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.lp_lock_token_account.to_account_info(),
+                    to: self.beneficiary_token_account.to_account_info(),
+                    authority: lp_lock.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        emit!(LpUnlockEvent {
+            mint: lp_lock.mint,
+            lp_mint: lp_lock.lp_mint,
+            beneficiary: self.beneficiary.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{
+    constants::{BONDING_CURVE, CONFIG, GLOBAL, METADATA},
+    errors::PumpfunError,
+    events::MetadataUpdatedEvent,
+    state::{BondingCurve, Config},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    metadata::{self, mpl_token_metadata::types::DataV2, Metadata, MetadataAccount},
+    token_2022::{
+        self,
+        spl_token_2022::{
+            self,
+            extension::{BaseStateWithExtensions, StateWithExtensions},
+        },
+    },
+    token_2022_extensions::spl_token_metadata_interface::state::{Field, TokenMetadata},
+    token_interface::{self, Mint, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    /// CHECK: global vault pda, update authority of the metadata
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    global_vault: AccountInfo<'info>,
+
+    //  token-2022 metadata lives in the mint
+    #[account(mut, mint::token_program = token_program)]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump,
+        has_one = creator @PumpfunError::IncorrectAuthority,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    /// CHECK: passed to token metadata program, only used by spl token launches
+    #[account(
+        mut,
+        seeds = [
+            METADATA.as_bytes(),
+            metadata::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = metadata::ID
+    )]
+    token_metadata_account: Option<UncheckedAccount<'info>>,
+
+    mpl_token_metadata_program: Option<Program<'info, Metadata>>,
+
+    token_program: Interface<'info, TokenInterface>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateMetadata<'info> {
+    pub fn process(
+        &mut self,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+        lock_on_migration: Option<bool>,
+        global_vault_bump: u8,
+    ) -> Result<()> {
+        require!(
+            !self.bonding_curve.is_completed,
+            PumpfunError::CurveAlreadyCompleted
+        );
+
+        let metadata_rules = &self.global_config.metadata_rules;
+        if let Some(name) = &name {
+            metadata_rules.check_name(name)?;
+        }
+        if let Some(symbol) = &symbol {
+            metadata_rules.check_symbol(symbol)?;
+        }
+        if let Some(uri) = &uri {
+            metadata_rules.check_uri(uri)?;
+        }
+
+        if name.is_none() && symbol.is_none() && uri.is_none() {
+            return self.set_lock_on_migration(lock_on_migration);
+        }
+
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        let (name, symbol, uri) = if self.token_program.key() == token_2022::ID {
+            self.update_token_2022_metadata(name, symbol, uri, signer_seeds)?
+        } else {
+            self.update_metaplex_metadata(name, symbol, uri, signer_seeds)?
+        };
+
+        emit!(MetadataUpdatedEvent {
+            mint: self.token_mint.key(),
+            creator: self.creator.key(),
+            name,
+            symbol,
+            uri,
+        });
+
+        self.set_lock_on_migration(lock_on_migration)
+    }
+
+    //  migration can only lock metadata the global vault is the update authority of,
+    //  otherwise the lock would fail and the curve could never migrate
+    fn set_lock_on_migration(&mut self, lock_on_migration: Option<bool>) -> Result<()> {
+        let Some(lock_on_migration) = lock_on_migration else {
+            return Ok(());
+        };
+
+        if lock_on_migration {
+            let update_authority = if self.token_program.key() == token_2022::ID {
+                let mint_info = self.token_mint.to_account_info();
+                let data = mint_info.try_borrow_data()?;
+                let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+                let Ok(token_metadata) = mint_state.get_variable_len_extension::<TokenMetadata>()
+                else {
+                    return err!(PumpfunError::MetadataNotLockable);
+                };
+                Option::<Pubkey>::from(token_metadata.update_authority)
+            } else {
+                let Some(token_metadata_account) = self.token_metadata_account.as_ref() else {
+                    return err!(PumpfunError::MissingMetadataAccounts);
+                };
+                if token_metadata_account.owner != &metadata::ID
+                    || token_metadata_account.data_is_empty()
+                {
+                    return err!(PumpfunError::MetadataNotLockable);
+                }
+                let metadata = MetadataAccount::try_deserialize(
+                    &mut &token_metadata_account.try_borrow_data()?[..],
+                )?;
+                metadata.is_mutable.then_some(metadata.update_authority)
+            };
+
+            require!(
+                update_authority == Some(self.global_vault.key()),
+                PumpfunError::MetadataNotLockable
+            );
+        }
+
+        self.bonding_curve.lock_metadata_on_migration = lock_on_migration;
+        Ok(())
+    }
+
+    //  returns the metadata after the update
+    fn update_metaplex_metadata(
+        &self,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<(String, String, String)> {
+        let (Some(token_metadata_account), Some(mpl_token_metadata_program)) = (
+            self.token_metadata_account.as_ref(),
+            self.mpl_token_metadata_program.as_ref(),
+        ) else {
+            return err!(PumpfunError::MissingMetadataAccounts);
+        };
+
+        let current = MetadataAccount::try_deserialize(
+            &mut &token_metadata_account.try_borrow_data()?[..],
+        )?;
+
+        //  metaplex pads strings with null bytes
+        let data = DataV2 {
+            name: name.unwrap_or_else(|| current.name.trim_end_matches('\0').to_string()),
+            symbol: symbol.unwrap_or_else(|| current.symbol.trim_end_matches('\0').to_string()),
+            uri: uri.unwrap_or_else(|| current.uri.trim_end_matches('\0').to_string()),
+            seller_fee_basis_points: current.seller_fee_basis_points,
+            creators: current.creators.clone(),
+            collection: current.collection.clone(),
+            uses: current.uses.clone(),
+        };
+        let updated = (data.name.clone(), data.symbol.clone(), data.uri.clone());
+
+        metadata::update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                mpl_token_metadata_program.to_account_info(),
+                metadata::UpdateMetadataAccountsV2 {
+                    metadata: token_metadata_account.to_account_info(),
+                    update_authority: self.global_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            None,
+            Some(data),
+            None,
+            None,
+        )?;
+
+        Ok(updated)
+    }
+
+    //  returns the metadata after the update
+    fn update_token_2022_metadata(
+        &self,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<(String, String, String)> {
+        let mint_info = self.token_mint.to_account_info();
+
+        let (current_len, mut token_metadata) = {
+            let data = mint_info.try_borrow_data()?;
+            let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+            (
+                data.len(),
+                mint_state.get_variable_len_extension::<TokenMetadata>()?,
+            )
+        };
+        let current_tlv_size = token_metadata.tlv_size_of()?;
+
+        let mut fields = vec![];
+        if let Some(name) = name {
+            token_metadata.name = name.clone();
+            fields.push((Field::Name, name));
+        }
+        if let Some(symbol) = symbol {
+            token_metadata.symbol = symbol.clone();
+            fields.push((Field::Symbol, symbol));
+        }
+        if let Some(uri) = uri {
+            token_metadata.uri = uri.clone();
+            fields.push((Field::Uri, uri));
+        }
+
+        //  token-2022 reallocs the mint for longer values, so fund the extra rent first
+        let new_len = (current_len + token_metadata.tlv_size_of()?).saturating_sub(current_tlv_size);
+        let lamport_delta = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(mint_info.lamports());
+        if lamport_delta > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: self.creator.to_account_info(),
+                        to: mint_info.clone(),
+                    },
+                ),
+                lamport_delta,
+            )?;
+        }
+
+        for (field, value) in fields {
+            token_interface::token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token_interface::TokenMetadataUpdateField {
+                        token_program_id: self.token_program.to_account_info(),
+                        metadata: mint_info.clone(),
+                        update_authority: self.global_vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                field,
+                value,
+            )?;
+        }
+
+        Ok((token_metadata.name, token_metadata.symbol, token_metadata.uri))
+    }
+}
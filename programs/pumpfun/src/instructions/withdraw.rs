@@ -3,12 +3,12 @@ use crate::{
     errors::*,
     events::WithdrawEvent, 
     state::{BondingCurve, Config, BondingCurveAccount}, 
-    utils::{sol_transfer_with_signer, token_transfer_with_signer}
+    utils::{check_ata_address, create_ata_if_needed, sol_transfer_with_signer, token_transfer_with_signer}
 };
 use anchor_lang::{prelude::*, system_program};
 use anchor_spl::{
     associated_token::{self, AssociatedToken}, 
-    token::{self, Mint, Token},
+    token_interface::{Mint, TokenInterface},
 };
 
 #[derive(Accounts)]
@@ -34,7 +34,8 @@ pub struct Withdraw<'info> {
     )]
     admin: Signer<'info>,
 
-    token_mint: Box<Account<'info, Mint>>,
+    #[account(mint::token_program = token_program)]
+    token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
@@ -48,7 +49,7 @@ pub struct Withdraw<'info> {
         mut,
         seeds = [
             global_vault.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
+            token_program.key().as_ref(),
             token_mint.key().as_ref(),
         ],
         bump,
@@ -61,7 +62,7 @@ pub struct Withdraw<'info> {
         mut,
         seeds = [
             admin.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
+            token_program.key().as_ref(),
             token_mint.key().as_ref(),
         ],
         bump,
@@ -72,11 +73,27 @@ pub struct Withdraw<'info> {
     #[account(address = system_program::ID)]
     system_program: Program<'info, System>,
 
-    #[account(address = token::ID)]
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
 
     #[account(address = associated_token::ID)]
     associated_token_program: Program<'info, AssociatedToken>,
+
+    //  quote accounts, only for curves priced in an spl quote mint
+    #[account(
+        address = bonding_curve.quote_mint @PumpfunError::InvalidQuoteMint,
+        mint::token_program = quote_token_program,
+    )]
+    quote_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// CHECK: quote ata of global vault, checked in instruction
+    #[account(mut)]
+    global_quote_ata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: quote ata of admin, checked in instruction
+    #[account(mut)]
+    admin_quote_ata: Option<UncheckedAccount<'info>>,
+
+    quote_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 impl<'info> Withdraw<'info> {
@@ -89,6 +106,8 @@ pub fn process(
     let admin_ata = &mut self.admin_ata;
 
     require!(bonding_curve.is_completed == true, PumpfunError::CurveNotCompleted);
+    //  funds of a settled curve are held for its pool
+    require!(!bonding_curve.is_fee_settled, PumpfunError::FeeAlreadySettled);
 
     //  create admin wallet ata, if it doesn't exist
     if admin_ata.data_is_empty() {
@@ -113,24 +132,63 @@ pub fn process(
         &[global_vault_bump],
     ]];
 
-    sol_transfer_with_signer(
-        self.global_vault.clone(),
-        self.admin.to_account_info(),
-        &self.system_program,
-        signer_seeds,
-        lamport_amount,
-    )?;
+    if bonding_curve.is_sol_quote() {
+        sol_transfer_with_signer(
+            self.global_vault.clone(),
+            self.admin.to_account_info(),
+            &self.system_program,
+            signer_seeds,
+            lamport_amount,
+        )?;
+    } else {
+        let (
+            Some(quote_mint),
+            Some(global_quote_ata),
+            Some(admin_quote_ata),
+            Some(quote_token_program),
+        ) = (
+            self.quote_mint.as_deref(),
+            self.global_quote_ata.as_ref(),
+            self.admin_quote_ata.as_ref(),
+            self.quote_token_program.as_ref(),
+        ) else {
+            return err!(PumpfunError::InvalidQuoteAccount);
+        };
+
+        check_ata_address(global_quote_ata, &self.global_vault.key(), &quote_mint.key(), &quote_token_program.key())?;
+        check_ata_address(admin_quote_ata, &self.admin.key(), &quote_mint.key(), &quote_token_program.key())?;
+        create_ata_if_needed(
+            self.admin.to_account_info(),
+            admin_quote_ata.to_account_info(),
+            self.admin.to_account_info(),
+            quote_mint.to_account_info(),
+            quote_token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            self.associated_token_program.to_account_info(),
+        )?;
+
+        token_transfer_with_signer(
+            global_quote_ata.to_account_info(),
+            self.global_vault.clone(),
+            admin_quote_ata.to_account_info(),
+            quote_mint,
+            quote_token_program,
+            signer_seeds,
+            lamport_amount,
+        )?;
+    }
 
     token_transfer_with_signer(
         self.global_vault_ata.clone(),
         self.global_vault.clone(),
         self.admin_ata.clone(),
+        &self.token_mint,
         &self.token_program,
         signer_seeds,
         bonding_curve.reserve_token,
     )?;
 
-    bonding_curve.update_reserves(global_config, 0, 0)?;
+    bonding_curve.update_reserves(global_config.curve_limit, 0, 0)?;
 
     emit!(
         WithdrawEvent {
@@ -1,14 +1,20 @@
 pub mod amm_instruction;
 pub mod constants;
+pub mod cpmm_instruction;
+pub mod damm_instruction;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod migration;
 pub mod state;
 pub mod utils;
 
 use crate::instructions::*;
 use anchor_lang::prelude::*;
-use state::Config;
+use state::{
+    AirdropParams, AllocationTable, AmountConfig, AuthorityRole, Config, LpPolicy, MetadataRules,
+    MigrationTarget, TransferFeeParams,
+};
 
 declare_id!("ApRXrsZcqKHzQFrdYYKcPhe66S5oHMwWqnC9DZVqiZFM");
 
@@ -22,16 +28,196 @@ pub mod pumpfun {
         ctx.accounts.process(new_config, ctx.bumps.config)
     }
 
-    //  Admin can hand over admin role
-    pub fn nominate_authority(ctx: Context<NominateAuthority>, new_admin: Pubkey) -> Result<()> {
-        ctx.accounts.process(new_admin)
+    //  Admin creates the platform collection once, spl token launches are verified into it
+    pub fn create_collection(
+        ctx: Context<CreateCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(name, symbol, uri, ctx.bumps.global_vault)
+    }
+
+    //  Admin can hand over admin, global authority or team wallet role
+    //  expiry - optional unix timestamp after which the nomination can't be accepted
+    pub fn nominate_authority(
+        ctx: Context<NominateAuthority>,
+        role: AuthorityRole,
+        new_admin: Pubkey,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts.process(role, new_admin, expiry)
+    }
+
+    //  Pending admin should accept the role
+    pub fn accept_authority(ctx: Context<AcceptAuthority>, role: AuthorityRole) -> Result<()> {
+        ctx.accounts.process(role)
+    }
+
+    //  Admin can cancel a pending nomination
+    pub fn cancel_authority(ctx: Context<CancelAuthority>, role: AuthorityRole) -> Result<()> {
+        ctx.accounts.process(role)
+    }
+
+    //  Admin can update fees without resending the whole config
+    pub fn set_fees(
+        ctx: Context<SetFees>,
+        platform_buy_fee: f64,
+        platform_sell_fee: f64,
+        platform_migration_fee: f64,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(platform_buy_fee, platform_sell_fee, platform_migration_fee)
     }
 
-    //  Pending admin should accept the admin role
-    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    pub fn set_curve_limit(ctx: Context<SetCurveLimit>, curve_limit: u64) -> Result<()> {
+        ctx.accounts.process(curve_limit)
+    }
+
+    pub fn set_launch_limits(
+        ctx: Context<SetLaunchLimits>,
+        lamport_amount_config: AmountConfig<u64>,
+        token_supply_config: AmountConfig<u64>,
+        token_decimals_config: AmountConfig<u8>,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            lamport_amount_config,
+            token_supply_config,
+            token_decimals_config,
+        )
+    }
+
+    //  Admin approves an spl quote mint for new curves, or disables it
+    pub fn set_quote_mint(
+        ctx: Context<SetQuoteMint>,
+        enabled: bool,
+        curve_limit: u64,
+        min_init_reserve: u64,
+        max_init_reserve: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(enabled, curve_limit, min_init_reserve, max_init_reserve)
+    }
+
+    //  cliff and duration in seconds of the team allocation vesting of new launches
+    pub fn set_vesting_schedule(
+        ctx: Context<SetVestingSchedule>,
+        vesting_cliff: i64,
+        vesting_duration: i64,
+    ) -> Result<()> {
+        ctx.accounts.process(vesting_cliff, vesting_duration)
+    }
+
+    //  bounds of the allocation table creators can choose at launch, in basis points
+    pub fn set_allocation_limits(
+        ctx: Context<SetAllocationLimits>,
+        min_curve_bps: u16,
+        max_creator_bps: u16,
+        max_team_bps: u16,
+        max_airdrop_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(min_curve_bps, max_creator_bps, max_team_bps, max_airdrop_bps)
+    }
+
+    //  seconds after launch recipients can claim the airdrop of new launches
+    pub fn set_airdrop_claim_window(
+        ctx: Context<SetAirdropClaimWindow>,
+        airdrop_claim_window: i64,
+    ) -> Result<()> {
+        ctx.accounts.process(airdrop_claim_window)
+    }
+
+    //  length, charset and uri prefix rules for launch metadata
+    pub fn set_metadata_rules(
+        ctx: Context<SetMetadataRules>,
+        metadata_rules: MetadataRules,
+    ) -> Result<()> {
+        ctx.accounts.process(metadata_rules)
+    }
+
+    //  cooldown and window in seconds, zero disables the limit
+    pub fn set_launch_rate_limits(
+        ctx: Context<SetLaunchRateLimits>,
+        launch_cooldown: i64,
+        launch_window: i64,
+        max_launches_per_window: u32,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(launch_cooldown, launch_window, max_launches_per_window)
+    }
+
+    //  fee escrowed at launch, refunded to the creator or forwarded to the team wallet on graduation
+    pub fn set_launch_fee(
+        ctx: Context<SetLaunchFee>,
+        launch_fee_lamports: u64,
+        refund_launch_fee_on_graduation: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(launch_fee_lamports, refund_launch_fee_on_graduation)
+    }
+
+    //  share of the curve proceeds paid to the creator and the completing buyer on migration
+    pub fn set_graduation_rewards(
+        ctx: Context<SetGraduationRewards>,
+        creator_graduation_reward_bps: u16,
+        completer_graduation_reward_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            creator_graduation_reward_bps,
+            completer_graduation_reward_bps,
+        )
+    }
+
+    //  lamports paid from the curve to whoever runs the migration
+    pub fn set_crank_reward(
+        ctx: Context<SetCrankReward>,
+        migration_crank_reward_lamports: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(migration_crank_reward_lamports)
+    }
+
+    //  SOL held back from graduating curves for raydium and openbook fees, 0 uses the defaults
+    pub fn set_migration_costs(
+        ctx: Context<SetMigrationCosts>,
+        migration_cost_lamports: u64,
+        market_cost_lamports: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(migration_cost_lamports, market_cost_lamports)
+    }
+
+    //  dex of launches which don't choose one
+    pub fn set_migration_target(
+        ctx: Context<SetMigrationTarget>,
+        migration_target: MigrationTarget,
+    ) -> Result<()> {
+        ctx.accounts.process(migration_target)
+    }
+
+    //  burn or lock the lp tokens of new pools, a lock duration of 0 locks them forever
+    pub fn set_lp_policy(
+        ctx: Context<SetLpPolicy>,
+        lp_policy: LpPolicy,
+        lp_lock_duration: i64,
+    ) -> Result<()> {
+        ctx.accounts.process(lp_policy, lp_lock_duration)
+    }
+
+    //  upgrade accounts created with an older layout in place
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn migrate_bonding_curve(ctx: Context<MigrateBondingCurve>) -> Result<()> {
         ctx.accounts.process()
     }
 
+    pub fn migrate_whitelist(ctx: Context<MigrateWhitelist>, creator: Pubkey) -> Result<()> {
+        ctx.accounts.process(creator)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn launch(
         ctx: Context<Launch>,
 
@@ -44,6 +230,21 @@ pub mod pumpfun {
         name: String,
         symbol: String,
         uri: String,
+
+        //  merkle proof of the creator, empty when whitelisted by pda
+        creator_proof: Vec<[u8; 32]>,
+
+        //  token-2022 only
+        transfer_fee: Option<TransferFeeParams>,
+
+        //  supply split in basis points, config split when none
+        allocation: Option<AllocationTable>,
+
+        //  required when the allocation has an airdrop share
+        airdrop: Option<AirdropParams>,
+
+        //  dex the curve migrates to, config default when none
+        migration_target: Option<MigrationTarget>,
     ) -> Result<()> {
         ctx.accounts.process(
             decimals,
@@ -52,10 +253,68 @@ pub mod pumpfun {
             name,
             symbol,
             uri,
+            creator_proof,
+            transfer_fee,
+            allocation,
+            airdrop,
+            migration_target,
             ctx.bumps.global_vault,
+            ctx.bumps.vesting,
+            ctx.bumps.distributor,
         )
     }
 
+    //  create a bonding curve for a mint whose mint and freeze authorities are revoked
+    //  the curve allocation is pulled from the creator's token account
+    pub fn list_existing(
+        ctx: Context<ListExisting>,
+        virtual_lamport_reserves: u64,
+
+        //  merkle proof of the creator, empty when whitelisted by pda
+        creator_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(virtual_lamport_reserves, creator_proof)
+    }
+
+    //  beneficiary claims the vested part of the team allocation
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    //  beneficiary claims the locked lp tokens of a migrated pool once they unlock
+    pub fn unlock_lp(ctx: Context<UnlockLp>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    //  recipient claims its airdrop leaf with a merkle proof
+    pub fn claim_airdrop(
+        ctx: Context<ClaimAirdrop>,
+        index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.process(index, amount, proof)
+    }
+
+    //  admin takes back unclaimed airdrop tokens after the claim window
+    pub fn clawback_airdrop(ctx: Context<ClawbackAirdrop>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    //  creator fixes name, symbol or uri while the curve is trading
+    //  lock_on_migration - make the metadata immutable when the curve migrates
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+        lock_on_migration: Option<bool>,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(name, symbol, uri, lock_on_migration, ctx.bumps.global_vault)
+    }
+
     //  amount - swap amount
     //  direction - 0: buy, 1: sell
     pub fn swap(
@@ -89,6 +348,31 @@ pub mod pumpfun {
         ctx.accounts.process(ctx.bumps.global_vault)
     }
 
+    //  rotate the approved creators in one transaction
+    pub fn set_creator_merkle_root(
+        ctx: Context<SetCreatorMerkleRoot>,
+        creator_merkle_root: [u8; 32],
+        whitelist_enabled: bool,
+    ) -> Result<()> {
+        ctx.accounts.process(creator_merkle_root, whitelist_enabled)
+    }
+
+    pub fn set_compliance_authority(
+        ctx: Context<SetComplianceAuthority>,
+        compliance_authority: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.process(compliance_authority)
+    }
+
+    //  compliance authority can block a wallet from trading and launching
+    pub fn block_wallet(ctx: Context<BlockWallet>, wallet: Pubkey) -> Result<()> {
+        ctx.accounts.process(wallet)
+    }
+
+    pub fn unblock_wallet(ctx: Context<UnblockWallet>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     pub fn add_wl(ctx: Context<AddWl>, new_creator: Pubkey)-> Result<()> {
         AddWl::handler(ctx, new_creator)
     }
@@ -97,13 +381,24 @@ pub mod pumpfun {
         Ok(())
     }
 
-    pub fn migrate(
-        _ctx: Context<Migrate>,
-        _nonce: u8
-    ) -> Result<()>{
-        Ok(())
+    //  permissionless, anyone can run this after `transfer_fee` settled a completed curve
+    //  removes bonding curve and add liquidity to the curve's migration target
+    //  the caller earns the crank reward
+    pub fn migrate<'info>(ctx: Context<'_, '_, 'info, 'info, Migrate<'info>>) -> Result<()> {
+        ctx.accounts.process(
+            ctx.remaining_accounts,
+            ctx.bumps.global_vault,
+            ctx.bumps.lp_lock,
+        )
+    }
+
+    //  `transfer_fee` and `migrate` atomically, nothing is left in wsol if the pool fails
+    pub fn graduate<'info>(ctx: Context<'_, '_, 'info, 'info, Graduate<'info>>) -> Result<()> {
+        ctx.accounts.process(
+            ctx.remaining_accounts,
+            ctx.bumps.settle.global_vault,
+            ctx.bumps.lp_lock,
+        )
     }
-    //  backend receives a event when the curve is copmleted and run this instruction
-    //  removes bonding curve and add liquidity to raydium
 
 }
@@ -0,0 +1,143 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+
+use crate::{
+    damm_instruction::{self, vault_program, LP_MINT_SEED},
+    errors::PumpfunError,
+};
+
+use super::{
+    check_account_key, check_lp_account, check_pda, MigrationPool, PoolAccounts, PoolDeposit,
+    PoolLp,
+};
+
+//  meteora dynamic amm constant product pool with config fees, coin is token a
+//
+//  0. dynamic amm program
+//  1. pool
+//  2. pool config, owned by the dynamic amm program
+//  3. lp mint
+//  4. vault of the coin mint
+//  5. vault of the pc mint
+//  6. token account of the coin vault
+//  7. token account of the pc vault
+//  8. lp mint of the coin vault
+//  9. lp mint of the pc vault
+//  10. coin vault lp held by the pool
+//  11. pc vault lp held by the pool
+//  12. lp token account of global vault, created by the dynamic amm program
+//  13. protocol fee account of the coin mint
+//  14. protocol fee account of the pc mint
+//  15. metadata of the lp mint
+//  16. token metadata program
+//  17. vault program
+pub struct MeteoraDamm;
+
+impl MigrationPool for MeteoraDamm {
+    const ACCOUNTS_LEN: usize = 18;
+    const NEEDS_MARKET: bool = false;
+    const SUPPORTS_TOKEN_2022: bool = false;
+
+    fn check_accounts(pool: &PoolAccounts, accounts: &[AccountInfo]) -> Result<()> {
+        let [damm_program, pool_account, config, lp_mint, _a_vault, _b_vault, _a_token_vault, _b_token_vault, _a_vault_lp_mint, _b_vault_lp_mint, _a_vault_lp, _b_vault_lp, lp_account, _protocol_token_a_fee, _protocol_token_b_fee, _mint_metadata, metadata_program, vault_program] =
+            accounts
+        else {
+            return err!(PumpfunError::MissingMigrationAccounts);
+        };
+
+        //  pinned since the vault signs the cpi, vaults are checked by the pinned programs
+        check_account_key(damm_program, &damm_instruction::ID)?;
+        check_account_key(vault_program, &vault_program::ID)?;
+        check_account_key(metadata_program, &anchor_spl::metadata::ID)?;
+        check_account_key(&pool.token_program, &anchor_spl::token::ID)?;
+        check_account_key(&pool.quote_token_program, &anchor_spl::token::ID)?;
+        require_keys_eq!(
+            *config.owner,
+            damm_instruction::ID,
+            PumpfunError::InvalidMigrationAccount
+        );
+
+        check_pda(
+            lp_mint,
+            &[LP_MINT_SEED.as_bytes(), pool_account.key.as_ref()],
+            damm_program.key,
+        )?;
+        check_lp_account(pool, lp_account, lp_mint)
+    }
+
+    fn create_pool<'info>(
+        pool: &PoolAccounts<'info>,
+        accounts: &[AccountInfo<'info>],
+        deposit: &PoolDeposit,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<PoolLp<'info>> {
+        let [damm_program, pool_account, config, lp_mint, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, lp_account, protocol_token_a_fee, protocol_token_b_fee, mint_metadata, metadata_program, vault_program] =
+            accounts
+        else {
+            return err!(PumpfunError::MissingMigrationAccounts);
+        };
+
+        let initialize_ix = damm_instruction::initialize_with_config(
+            damm_program.key,
+            pool_account.key,
+            config.key,
+            lp_mint.key,
+            pool.coin_mint.key,
+            pool.pc_mint.key,
+            a_vault.key,
+            b_vault.key,
+            a_token_vault.key,
+            b_token_vault.key,
+            a_vault_lp_mint.key,
+            b_vault_lp_mint.key,
+            a_vault_lp.key,
+            b_vault_lp.key,
+            pool.coin_account.key,
+            pool.pc_account.key,
+            lp_account.key,
+            protocol_token_a_fee.key,
+            protocol_token_b_fee.key,
+            //  vault holds the pool funds and pays for the pool
+            pool.global_vault.key,
+            mint_metadata.key,
+            metadata_program.key,
+            deposit.coin_amount,
+            deposit.pc_amount,
+        )?;
+        let account_infos = [
+            damm_program.clone(),
+            pool_account.clone(),
+            config.clone(),
+            lp_mint.clone(),
+            pool.coin_mint.clone(),
+            pool.pc_mint.clone(),
+            a_vault.clone(),
+            b_vault.clone(),
+            a_token_vault.clone(),
+            b_token_vault.clone(),
+            a_vault_lp_mint.clone(),
+            b_vault_lp_mint.clone(),
+            a_vault_lp.clone(),
+            b_vault_lp.clone(),
+            pool.coin_account.clone(),
+            pool.pc_account.clone(),
+            lp_account.clone(),
+            protocol_token_a_fee.clone(),
+            protocol_token_b_fee.clone(),
+            pool.global_vault.clone(),
+            pool.rent.clone(),
+            mint_metadata.clone(),
+            metadata_program.clone(),
+            vault_program.clone(),
+            pool.token_program.clone(),
+            pool.associated_token_program.clone(),
+            pool.system_program.clone(),
+        ];
+        invoke_signed(&initialize_ix, &account_infos, signer_seeds)?;
+
+        Ok(PoolLp {
+            lp_mint: lp_mint.clone(),
+            lp_account: lp_account.clone(),
+            token_program: pool.token_program.clone(),
+        })
+    }
+}
@@ -0,0 +1,361 @@
+//! Dexes a completed curve can migrate to
+//!
+//! Each target lists the accounts it needs after the common ones, passed as remaining
+//! accounts, computes the deposit and creates the pool with the global vault as signer.
+
+pub mod meteora_damm;
+pub mod raydium_amm_v4;
+pub mod raydium_cpmm;
+
+pub use meteora_damm::*;
+pub use raydium_amm_v4::*;
+pub use raydium_cpmm::*;
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token::{self, TokenAccount},
+};
+
+use crate::{
+    errors::PumpfunError,
+    events::MigrateEvent,
+    state::{BondingCurve, Config, LaunchStatus, LpLock, LpPolicy, MigrationTarget, RegistryPage},
+    utils::{create_ata_if_needed, sol_transfer_with_signer},
+};
+
+//  accounts owned by this program that every target uses
+pub struct PoolAccounts<'info> {
+    pub global_vault: AccountInfo<'info>,
+    pub coin_mint: AccountInfo<'info>,
+    pub pc_mint: AccountInfo<'info>,
+    //  vault token accounts funding the pool
+    pub coin_account: AccountInfo<'info>,
+    pub pc_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub quote_token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PoolDeposit {
+    pub coin_amount: u64,
+    pub pc_amount: u64,
+}
+
+//  lp side of a new pool, the lp tokens are minted to the global vault
+pub struct PoolLp<'info> {
+    pub lp_mint: AccountInfo<'info>,
+    pub lp_account: AccountInfo<'info>,
+    //  spl token for every target
+    pub token_program: AccountInfo<'info>,
+}
+
+//  lock pda of the curve and the ata holding its lp tokens, only for `LpPolicy::Lock`
+pub struct LpLockAccounts<'a, 'info> {
+    pub lp_lock: &'a mut Account<'info, LpLock>,
+    pub lp_lock_token_account: AccountInfo<'info>,
+    pub bump: u8,
+    //  pays for the ata of the lock
+    pub payer: AccountInfo<'info>,
+}
+
+//  caller of `migrate` or `graduate` and the lp lock accounts it passed
+pub struct MigrationCaller<'a, 'info> {
+    //  receives the crank reward, pays for the lock ata
+    pub payer: AccountInfo<'info>,
+    pub lp_lock: Option<&'a mut Account<'info, LpLock>>,
+    pub lp_lock_token_account: Option<AccountInfo<'info>>,
+    pub lp_lock_bump: Option<u8>,
+}
+
+pub trait MigrationPool {
+    //  number of target accounts, passed as remaining accounts in the order of the target
+    const ACCOUNTS_LEN: usize;
+    //  an openbook market has to be created by the caller first
+    const NEEDS_MARKET: bool;
+    const SUPPORTS_TOKEN_2022: bool;
+
+    //  same split as `transfer_fee`, the rest of the tokens went to the team wallet
+    fn deposit(bonding_curve: &BondingCurve) -> PoolDeposit {
+        let pc_amount = bonding_curve.pool_pc_amount;
+        let coin_amount = (pc_amount as u128 * bonding_curve.reserve_token as u128
+            / bonding_curve.reserve_lamport as u128) as u64;
+        PoolDeposit {
+            coin_amount,
+            pc_amount,
+        }
+    }
+
+    //  check the accounts the vault is about to sign for
+    fn check_accounts(pool: &PoolAccounts, accounts: &[AccountInfo]) -> Result<()>;
+
+    //  create the pool funded by the vault, returns where the lp tokens were minted
+    fn create_pool<'info>(
+        pool: &PoolAccounts<'info>,
+        accounts: &[AccountInfo<'info>],
+        deposit: &PoolDeposit,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<PoolLp<'info>>;
+}
+
+impl MigrationTarget {
+    pub fn needs_market(&self) -> bool {
+        match self {
+            MigrationTarget::RaydiumAmmV4 => RaydiumAmmV4::NEEDS_MARKET,
+            MigrationTarget::RaydiumCpmm => RaydiumCpmm::NEEDS_MARKET,
+            MigrationTarget::MeteoraDamm => MeteoraDamm::NEEDS_MARKET,
+        }
+    }
+
+    pub fn supports_token_2022(&self) -> bool {
+        match self {
+            MigrationTarget::RaydiumAmmV4 => RaydiumAmmV4::SUPPORTS_TOKEN_2022,
+            MigrationTarget::RaydiumCpmm => RaydiumCpmm::SUPPORTS_TOKEN_2022,
+            MigrationTarget::MeteoraDamm => MeteoraDamm::SUPPORTS_TOKEN_2022,
+        }
+    }
+
+    //  returns the deposit and the lp side of the new pool
+    pub fn create_pool<'info>(
+        &self,
+        pool: &PoolAccounts<'info>,
+        remaining_accounts: &[AccountInfo<'info>],
+        bonding_curve: &BondingCurve,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<(PoolDeposit, PoolLp<'info>)> {
+        match self {
+            MigrationTarget::RaydiumAmmV4 => create_pool_with::<RaydiumAmmV4>(
+                pool,
+                remaining_accounts,
+                bonding_curve,
+                signer_seeds,
+            ),
+            MigrationTarget::RaydiumCpmm => create_pool_with::<RaydiumCpmm>(
+                pool,
+                remaining_accounts,
+                bonding_curve,
+                signer_seeds,
+            ),
+            MigrationTarget::MeteoraDamm => create_pool_with::<MeteoraDamm>(
+                pool,
+                remaining_accounts,
+                bonding_curve,
+                signer_seeds,
+            ),
+        }
+    }
+}
+
+fn create_pool_with<'info, T: MigrationPool>(
+    pool: &PoolAccounts<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    bonding_curve: &BondingCurve,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<(PoolDeposit, PoolLp<'info>)> {
+    let Some(accounts) = remaining_accounts.get(..T::ACCOUNTS_LEN) else {
+        msg!("expected {:?} migration accounts", T::ACCOUNTS_LEN);
+        return err!(PumpfunError::MissingMigrationAccounts);
+    };
+    T::check_accounts(pool, accounts)?;
+
+    let deposit = T::deposit(bonding_curve);
+    let lp = T::create_pool(pool, accounts, &deposit, signer_seeds)?;
+
+    msg!(
+        "Migrate {:?}:: Token: {:?}  Quote: {:?}",
+        bonding_curve.migration_target,
+        deposit.coin_amount,
+        deposit.pc_amount
+    );
+    Ok((deposit, lp))
+}
+
+//  create the pool of a settled curve, secure its lp and pay the caller
+//  shared by `migrate` and `graduate`, the caller checks the curve can migrate
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_curve<'info>(
+    global_config: &Config,
+    bonding_curve: &mut Account<'info, BondingCurve>,
+    registry_page: Option<&mut RegistryPage>,
+    pool: &PoolAccounts<'info>,
+    caller: MigrationCaller<'_, 'info>,
+    system_program: &Program<'info, System>,
+    remaining_accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let (deposit, lp) = bonding_curve.migration_target.create_pool(
+        pool,
+        remaining_accounts,
+        bonding_curve,
+        signer_seeds,
+    )?;
+
+    let lock = match (
+        caller.lp_lock,
+        caller.lp_lock_token_account,
+        caller.lp_lock_bump,
+    ) {
+        (Some(lp_lock), Some(lp_lock_token_account), Some(bump)) => Some(LpLockAccounts {
+            lp_lock,
+            lp_lock_token_account,
+            bump,
+            payer: caller.payer.clone(),
+        }),
+        _ => None,
+    };
+    let (lp_amount, lp_unlock_ts) = secure_lp(global_config, pool, &lp, lock, signer_seeds)?;
+
+    //  held back by `transfer_fee`, the market cost is included
+    let crank_reward = bonding_curve.crank_reward;
+    if crank_reward > 0 {
+        sol_transfer_with_signer(
+            pool.global_vault.clone(),
+            caller.payer,
+            system_program,
+            signer_seeds,
+            crank_reward,
+        )?;
+        msg!("Crank reward and market cost: {:?}", crank_reward);
+    }
+
+    bonding_curve.is_migrated = true;
+
+    RegistryPage::set_status(registry_page, bonding_curve, LaunchStatus::Migrated)?;
+
+    emit!(MigrateEvent {
+        token: pool.coin_mint.key(),
+        bonding_curve: bonding_curve.key(),
+        token_in: deposit.coin_amount,
+        sol_in: deposit.pc_amount,
+        lp_mint: lp.lp_mint.key(),
+        lp_amount,
+        lp_policy: global_config.lp_policy,
+        lp_unlock_ts,
+    });
+
+    Ok(())
+}
+
+//  burn the lp tokens or move them into the lock of the curve, per the config policy
+//  returns the lp amount and the unlock timestamp, 0 when burned or locked forever
+pub fn secure_lp<'info>(
+    global_config: &Config,
+    pool: &PoolAccounts<'info>,
+    lp: &PoolLp<'info>,
+    lock: Option<LpLockAccounts<'_, 'info>>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<(u64, i64)> {
+    let amount = TokenAccount::try_deserialize(&mut &lp.lp_account.try_borrow_data()?[..])?.amount;
+
+    match global_config.lp_policy {
+        LpPolicy::Burn => {
+            token::burn(
+                CpiContext::new_with_signer(
+                    lp.token_program.clone(),
+                    token::Burn {
+                        mint: lp.lp_mint.clone(),
+                        from: lp.lp_account.clone(),
+                        authority: pool.global_vault.clone(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+            msg!("Burned lp: {:?}", amount);
+
+            Ok((amount, 0))
+        }
+        LpPolicy::Lock => {
+            let Some(lock) = lock else {
+                return err!(PumpfunError::MissingLpLock);
+            };
+            let lp_lock = lock.lp_lock;
+
+            check_account_key(
+                &lock.lp_lock_token_account,
+                &get_associated_token_address_with_program_id(
+                    &lp_lock.key(),
+                    lp.lp_mint.key,
+                    lp.token_program.key,
+                ),
+            )?;
+            create_ata_if_needed(
+                lock.payer,
+                lock.lp_lock_token_account.clone(),
+                lp_lock.to_account_info(),
+                lp.lp_mint.clone(),
+                lp.token_program.clone(),
+                pool.system_program.clone(),
+                pool.associated_token_program.clone(),
+            )?;
+            token::transfer(
+                CpiContext::new_with_signer(
+                    lp.token_program.clone(),
+                    token::Transfer {
+                        from: lp.lp_account.clone(),
+                        to: lock.lp_lock_token_account,
+                        authority: pool.global_vault.clone(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+
+            let unlock_ts = if global_config.lp_lock_duration > 0 {
+                Clock::get()?
+                    .unix_timestamp
+                    .checked_add(global_config.lp_lock_duration)
+                    .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?
+            } else {
+                0
+            };
+
+            lp_lock.version = LpLock::VERSION;
+            lp_lock.mint = pool.coin_mint.key();
+            lp_lock.lp_mint = lp.lp_mint.key();
+            lp_lock.beneficiary = global_config.team_wallet;
+            lp_lock.amount = amount;
+            lp_lock.unlock_ts = unlock_ts;
+            lp_lock.bump = lock.bump;
+            msg!("Locked lp: {:?} until {:?}", amount, unlock_ts);
+
+            Ok((amount, unlock_ts))
+        }
+    }
+}
+
+pub fn check_account_key(account: &AccountInfo, expected: &Pubkey) -> Result<()> {
+    if account.key() != *expected {
+        msg!(
+            "expected migration account {expected:?}, got {:?}",
+            account.key()
+        );
+        return err!(PumpfunError::InvalidMigrationAccount);
+    }
+    Ok(())
+}
+
+pub fn check_pda(account: &AccountInfo, seeds: &[&[u8]], program_id: &Pubkey) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(seeds, program_id);
+    check_account_key(account, &expected)?;
+    Ok(bump)
+}
+
+//  lp tokens of the pool are minted to an ata of the global vault, then burned or locked
+pub fn check_lp_account(
+    pool: &PoolAccounts,
+    lp_account: &AccountInfo,
+    lp_mint: &AccountInfo,
+) -> Result<()> {
+    check_account_key(
+        lp_account,
+        &get_associated_token_address_with_program_id(
+            pool.global_vault.key,
+            lp_mint.key,
+            &anchor_spl::token::ID,
+        ),
+    )
+}
@@ -0,0 +1,187 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+
+use crate::{
+    amm_instruction::{self, openbook_program},
+    errors::PumpfunError,
+};
+
+use super::{
+    check_account_key, check_lp_account, check_pda, MigrationPool, PoolAccounts, PoolDeposit,
+    PoolLp,
+};
+
+//  raydium amm v4 `initialize2`, the caller creates the openbook market beforehand
+//  and is paid the market cost held back by `transfer_fee`
+//
+//  0. amm program
+//  1. amm pool
+//  2. amm authority
+//  3. amm open orders
+//  4. lp mint
+//  5. coin vault
+//  6. pc vault
+//  7. target orders
+//  8. amm config
+//  9. pool creation fee receiver, checked by the amm program against amm config
+//  10. openbook program
+//  11. openbook market
+//  12. lp token account of global vault, created by the amm program
+pub struct RaydiumAmmV4;
+
+impl RaydiumAmmV4 {
+    const AMM_AUTHORITY_SEED: &'static [u8] = b"amm authority";
+    //  coin mint then pc mint in the openbook market state, after the padding, flags, address and nonce
+    const MARKET_MINTS_OFFSET: usize = 5 + 8 + 32 + 8;
+
+    fn market_pda(
+        account: &AccountInfo,
+        amm_program: &AccountInfo,
+        market: &AccountInfo,
+        seed: &[u8],
+    ) -> Result<u8> {
+        check_pda(
+            account,
+            &[amm_program.key.as_ref(), market.key.as_ref(), seed],
+            amm_program.key,
+        )
+    }
+
+    //  market of the pool pair, the caller supplying it is paid the market cost
+    fn check_market(pool: &PoolAccounts, market: &AccountInfo) -> Result<()> {
+        require_keys_eq!(
+            *market.owner,
+            openbook_program::ID,
+            PumpfunError::InvalidMigrationAccount
+        );
+        let data = market.try_borrow_data()?;
+        let Some(mints) = data.get(Self::MARKET_MINTS_OFFSET..Self::MARKET_MINTS_OFFSET + 64)
+        else {
+            return err!(PumpfunError::InvalidMigrationAccount);
+        };
+        require!(
+            mints[..32] == pool.coin_mint.key.to_bytes()
+                && mints[32..] == pool.pc_mint.key.to_bytes(),
+            PumpfunError::InvalidMigrationAccount
+        );
+        Ok(())
+    }
+}
+
+impl MigrationPool for RaydiumAmmV4 {
+    const ACCOUNTS_LEN: usize = 13;
+    const NEEDS_MARKET: bool = true;
+    const SUPPORTS_TOKEN_2022: bool = false;
+
+    fn check_accounts(pool: &PoolAccounts, accounts: &[AccountInfo]) -> Result<()> {
+        let [amm_program, amm, amm_authority, amm_open_orders, lp_mint, coin_vault, pc_vault, target_orders, amm_config, _create_fee_destination, market_program, market, lp_account] =
+            accounts
+        else {
+            return err!(PumpfunError::MissingMigrationAccounts);
+        };
+
+        //  pinned since the vault signs the cpi
+        check_account_key(amm_program, &amm_instruction::ID)?;
+        check_account_key(market_program, &openbook_program::ID)?;
+        check_account_key(&pool.token_program, &anchor_spl::token::ID)?;
+        check_account_key(&pool.quote_token_program, &anchor_spl::token::ID)?;
+
+        Self::market_pda(amm, amm_program, market, b"amm_associated_seed")?;
+        Self::market_pda(
+            amm_open_orders,
+            amm_program,
+            market,
+            b"open_order_associated_seed",
+        )?;
+        Self::market_pda(lp_mint, amm_program, market, b"lp_mint_associated_seed")?;
+        Self::market_pda(
+            coin_vault,
+            amm_program,
+            market,
+            b"coin_vault_associated_seed",
+        )?;
+        Self::market_pda(pc_vault, amm_program, market, b"pc_vault_associated_seed")?;
+        Self::market_pda(
+            target_orders,
+            amm_program,
+            market,
+            b"target_associated_seed",
+        )?;
+        check_pda(amm_authority, &[Self::AMM_AUTHORITY_SEED], amm_program.key)?;
+        check_pda(amm_config, &[b"amm_config_account_seed"], amm_program.key)?;
+        Self::check_market(pool, market)?;
+        check_lp_account(pool, lp_account, lp_mint)
+    }
+
+    fn create_pool<'info>(
+        pool: &PoolAccounts<'info>,
+        accounts: &[AccountInfo<'info>],
+        deposit: &PoolDeposit,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<PoolLp<'info>> {
+        let [amm_program, amm, amm_authority, amm_open_orders, lp_mint, coin_vault, pc_vault, target_orders, amm_config, create_fee_destination, market_program, market, lp_account] =
+            accounts
+        else {
+            return err!(PumpfunError::MissingMigrationAccounts);
+        };
+
+        let (_, nonce) = Pubkey::find_program_address(&[Self::AMM_AUTHORITY_SEED], amm_program.key);
+
+        //  Running raydium amm initialize2
+        let initialize_ix = amm_instruction::initialize2(
+            amm_program.key,
+            amm.key,
+            amm_authority.key,
+            amm_open_orders.key,
+            lp_mint.key,
+            pool.coin_mint.key,
+            pool.pc_mint.key,
+            coin_vault.key,
+            pc_vault.key,
+            target_orders.key,
+            amm_config.key,
+            create_fee_destination.key,
+            market_program.key,
+            market.key,
+            //  vault holds the pool funds and pays for the pool
+            pool.global_vault.key,
+            pool.coin_account.key,
+            pool.pc_account.key,
+            lp_account.key,
+            nonce,
+            Clock::get()?.unix_timestamp as u64,
+            deposit.pc_amount,
+            deposit.coin_amount,
+        )?;
+        let account_infos = [
+            amm_program.clone(),
+            amm.clone(),
+            amm_authority.clone(),
+            amm_open_orders.clone(),
+            lp_mint.clone(),
+            pool.coin_mint.clone(),
+            pool.pc_mint.clone(),
+            coin_vault.clone(),
+            pc_vault.clone(),
+            target_orders.clone(),
+            amm_config.clone(),
+            create_fee_destination.clone(),
+            market_program.clone(),
+            market.clone(),
+            pool.global_vault.clone(),
+            pool.coin_account.clone(),
+            pool.pc_account.clone(),
+            lp_account.clone(),
+            pool.token_program.clone(),
+            pool.system_program.clone(),
+            pool.associated_token_program.clone(),
+            pool.rent.clone(),
+        ];
+        invoke_signed(&initialize_ix, &account_infos, signer_seeds)?;
+
+        Ok(PoolLp {
+            lp_mint: lp_mint.clone(),
+            lp_account: lp_account.clone(),
+            token_program: pool.token_program.clone(),
+        })
+    }
+}
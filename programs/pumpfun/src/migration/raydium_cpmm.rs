@@ -0,0 +1,183 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+
+use crate::{
+    cpmm_instruction::{
+        self, AUTH_SEED, OBSERVATION_SEED, POOL_LP_MINT_SEED, POOL_SEED, POOL_VAULT_SEED,
+    },
+    errors::PumpfunError,
+};
+
+use super::{
+    check_account_key, check_lp_account, check_pda, MigrationPool, PoolAccounts, PoolDeposit,
+    PoolLp,
+};
+
+//  raydium cpmm `initialize`, no openbook market is needed
+//
+//  0. cpmm program
+//  1. amm config, owned by the cpmm program
+//  2. vault and lp mint authority
+//  3. pool state
+//  4. lp mint
+//  5. coin vault
+//  6. pc vault
+//  7. observation state
+//  8. pool creation fee receiver, checked by the cpmm program
+//  9. lp token account of global vault, created by the cpmm program
+//  10. spl token program, owner of the lp mint
+pub struct RaydiumCpmm;
+
+impl MigrationPool for RaydiumCpmm {
+    const ACCOUNTS_LEN: usize = 11;
+    const NEEDS_MARKET: bool = false;
+    const SUPPORTS_TOKEN_2022: bool = true;
+
+    fn check_accounts(pool: &PoolAccounts, accounts: &[AccountInfo]) -> Result<()> {
+        let [cpmm_program, amm_config, authority, pool_state, lp_mint, coin_vault, pc_vault, observation_state, _create_pool_fee, lp_account, lp_token_program] =
+            accounts
+        else {
+            return err!(PumpfunError::MissingMigrationAccounts);
+        };
+
+        //  pinned since the vault signs the cpi
+        check_account_key(cpmm_program, &cpmm_instruction::ID)?;
+        check_account_key(lp_token_program, &anchor_spl::token::ID)?;
+        require_keys_eq!(
+            *amm_config.owner,
+            cpmm_instruction::ID,
+            PumpfunError::InvalidMigrationAccount
+        );
+
+        let (token_0_mint, token_1_mint) =
+            cpmm_instruction::sort_mints(pool.coin_mint.key(), pool.pc_mint.key());
+
+        check_pda(authority, &[AUTH_SEED.as_bytes()], cpmm_program.key)?;
+        check_pda(
+            pool_state,
+            &[
+                POOL_SEED.as_bytes(),
+                amm_config.key.as_ref(),
+                token_0_mint.as_ref(),
+                token_1_mint.as_ref(),
+            ],
+            cpmm_program.key,
+        )?;
+        check_pda(
+            lp_mint,
+            &[POOL_LP_MINT_SEED.as_bytes(), pool_state.key.as_ref()],
+            cpmm_program.key,
+        )?;
+        check_pda(
+            coin_vault,
+            &[
+                POOL_VAULT_SEED.as_bytes(),
+                pool_state.key.as_ref(),
+                pool.coin_mint.key.as_ref(),
+            ],
+            cpmm_program.key,
+        )?;
+        check_pda(
+            pc_vault,
+            &[
+                POOL_VAULT_SEED.as_bytes(),
+                pool_state.key.as_ref(),
+                pool.pc_mint.key.as_ref(),
+            ],
+            cpmm_program.key,
+        )?;
+        check_pda(
+            observation_state,
+            &[OBSERVATION_SEED.as_bytes(), pool_state.key.as_ref()],
+            cpmm_program.key,
+        )?;
+        check_lp_account(pool, lp_account, lp_mint)
+    }
+
+    fn create_pool<'info>(
+        pool: &PoolAccounts<'info>,
+        accounts: &[AccountInfo<'info>],
+        deposit: &PoolDeposit,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<PoolLp<'info>> {
+        let [cpmm_program, amm_config, authority, pool_state, lp_mint, coin_vault, pc_vault, observation_state, create_pool_fee, lp_account, lp_token_program] =
+            accounts
+        else {
+            return err!(PumpfunError::MissingMigrationAccounts);
+        };
+
+        //  cpmm orders the pool sides by mint address
+        let coin = (
+            &pool.coin_mint,
+            &pool.coin_account,
+            coin_vault,
+            &pool.token_program,
+            deposit.coin_amount,
+        );
+        let pc = (
+            &pool.pc_mint,
+            &pool.pc_account,
+            pc_vault,
+            &pool.quote_token_program,
+            deposit.pc_amount,
+        );
+        let (token_0, token_1) = if pool.coin_mint.key() < pool.pc_mint.key() {
+            (coin, pc)
+        } else {
+            (pc, coin)
+        };
+
+        let initialize_ix = cpmm_instruction::initialize(
+            cpmm_program.key,
+            //  vault holds the pool funds and pays for the pool
+            pool.global_vault.key,
+            amm_config.key,
+            authority.key,
+            pool_state.key,
+            token_0.0.key,
+            token_1.0.key,
+            lp_mint.key,
+            token_0.1.key,
+            token_1.1.key,
+            lp_account.key,
+            token_0.2.key,
+            token_1.2.key,
+            create_pool_fee.key,
+            observation_state.key,
+            token_0.3.key,
+            token_1.3.key,
+            token_0.4,
+            token_1.4,
+            Clock::get()?.unix_timestamp as u64,
+        )?;
+        let account_infos = [
+            cpmm_program.clone(),
+            pool.global_vault.clone(),
+            amm_config.clone(),
+            authority.clone(),
+            pool_state.clone(),
+            token_0.0.clone(),
+            token_1.0.clone(),
+            lp_mint.clone(),
+            token_0.1.clone(),
+            token_1.1.clone(),
+            lp_account.clone(),
+            token_0.2.clone(),
+            token_1.2.clone(),
+            create_pool_fee.clone(),
+            observation_state.clone(),
+            lp_token_program.clone(),
+            token_0.3.clone(),
+            token_1.3.clone(),
+            pool.associated_token_program.clone(),
+            pool.system_program.clone(),
+            pool.rent.clone(),
+        ];
+        invoke_signed(&initialize_ix, &account_infos, signer_seeds)?;
+
+        Ok(PoolLp {
+            lp_mint: lp_mint.clone(),
+            lp_account: lp_account.clone(),
+            token_program: lp_token_program.clone(),
+        })
+    }
+}
@@ -1,13 +1,15 @@
 use crate::constants::LAMPORT_DECIMALS;
 use crate::constants::GLOBAL;
+use crate::constants::MAX_TRANSFER_FEE_BPS;
 use crate::errors::*;
-use crate::events::CompleteEvent;
+use crate::events::{CompleteEvent, LaunchFeeReleased};
 use crate::utils::*;
 use anchor_lang::system_program;
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 use anchor_spl::token;
-use anchor_spl::token::Mint;
 use anchor_spl::token::Token;
+use anchor_spl::metadata::mpl_token_metadata::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+use anchor_spl::token_interface::{Mint, TokenInterface};
 use core::fmt::Debug;
 use std::ops::Div;
 use std::ops::Mul;
@@ -17,15 +19,159 @@ use std::ops::Sub;
 #[account]
 #[derive(InitSpace, Debug, Default)]
 pub struct Whitelist {
+    pub version: u8,
     pub creator: Pubkey,
+
+    //  reserved for future fields
+    pub padding: [u8; 32],
 }
 
 impl Whitelist {
     pub const SEED_PREFIX: &'static str = "wl-seed";
+    pub const VERSION: u8 = 1;
+}
+
+#[account]
+#[derive(InitSpace, Debug, Default)]
+pub struct Blocklist {
+    pub version: u8,
+    pub wallet: Pubkey,
+
+    //  reserved for future fields
+    pub padding: [u8; 32],
+}
+
+//  team allocation of a launch, released to the beneficiary over time
+#[account]
+#[derive(InitSpace, Debug, Default)]
+pub struct Vesting {
+    pub version: u8,
+    pub mint: Pubkey,
+    pub beneficiary: Pubkey,
+
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+
+    //  unix timestamps
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+
+    pub bump: u8,
+
+    //  reserved for future fields
+    pub padding: [u8; 32],
+}
+
+impl Vesting {
+    pub const SEED_PREFIX: &'static str = "vesting-seed";
+    pub const VERSION: u8 = 1;
+
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff_ts {
+            return 0;
+        }
+        if now >= self.end_ts {
+            return self.total_amount;
+        }
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+
+    pub fn claimable_amount(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.claimed_amount)
+    }
+}
+
+impl Blocklist {
+    pub const SEED_PREFIX: &'static str = "blocklist-seed";
+    pub const VERSION: u8 = 1;
+
+    //  the pda only exists while the wallet is blocked
+    pub fn check_not_blocked(blocklist: &AccountInfo) -> Result<()> {
+        if blocklist.owner == &crate::ID && !blocklist.data_is_empty() {
+            msg!("wallet {:?} is blocked", blocklist.key());
+            return err!(PumpfunError::WalletBlocked);
+        }
+        Ok(())
+    }
+}
+
+//  launch history of a creator, created on the first launch
+#[account]
+#[derive(InitSpace, Debug, Default)]
+pub struct CreatorProfile {
+    pub version: u8,
+    pub creator: Pubkey,
+
+    pub launch_count: u64,
+    pub graduated_count: u64,
+    pub last_launch_ts: i64,
+
+    //  launches in the current rate limit window
+    pub window_start_ts: i64,
+    pub window_launch_count: u32,
+
+    //  reserved for future fields
+    pub padding: [u8; 32],
+}
+
+impl CreatorProfile {
+    pub const SEED_PREFIX: &'static str = "creator-profile";
+    pub const VERSION: u8 = 1;
+
+    //  enforce cooldown and launches per window, then count the launch
+    pub fn record_launch(&mut self, creator: Pubkey, global_config: &Config, now: i64) -> Result<()> {
+        if self.launch_count > 0
+            && global_config.launch_cooldown > 0
+            && now < self.last_launch_ts + global_config.launch_cooldown
+        {
+            msg!(
+                "next launch allowed at {:?}",
+                self.last_launch_ts + global_config.launch_cooldown
+            );
+            return err!(PumpfunError::LaunchCooldown);
+        }
+
+        if global_config.launch_window > 0 {
+            if now >= self.window_start_ts + global_config.launch_window {
+                self.window_start_ts = now;
+                self.window_launch_count = 0;
+            }
+            if global_config.max_launches_per_window > 0
+                && self.window_launch_count >= global_config.max_launches_per_window
+            {
+                return err!(PumpfunError::LaunchRateLimited);
+            }
+            self.window_launch_count += 1;
+        }
+
+        self.version = Self::VERSION;
+        self.creator = creator;
+        self.launch_count += 1;
+        self.last_launch_ts = now;
+
+        Ok(())
+    }
+
+    //  creators who launched before profiles existed have no pda
+    pub fn record_graduation(profile: &AccountInfo) -> Result<()> {
+        if profile.owner != &crate::ID || profile.data_is_empty() {
+            return Ok(());
+        }
+        let mut data = profile.try_borrow_mut_data()?;
+        let mut state = CreatorProfile::try_deserialize(&mut &data[..])?;
+        state.graduated_count += 1;
+        state.try_serialize(&mut &mut data[..])
+    }
 }
 
 #[account]
 pub struct Config {
+    //  layout version, see `Config::VERSION`
+    pub version: u8,
+
     pub authority: Pubkey,
     //  use this for 2 step ownership transfer
     pub pending_authority: Pubkey,
@@ -34,76 +180,1060 @@ pub struct Config {
 
     pub init_bonding_curve: f64, // bonding curve init percentage. The remaining amount is sent to team wallet for distribution to agent
 
-    pub platform_buy_fee: f64, //  platform fee percentage
+    pub platform_buy_fee: f64, //  platform fee percentage
+    pub platform_sell_fee: f64,
+    pub platform_migration_fee: f64,
+
+    pub curve_limit: u64, //  lamports to complete te bonding curve
+
+    pub lamport_amount_config: AmountConfig<u64>,
+    pub token_supply_config: AmountConfig<u64>,
+    pub token_decimals_config: AmountConfig<u8>,
+
+    pub initialized: bool,
+    pub global_authority: Pubkey,    // can update settings
+
+    pub whitelist_enabled: bool,
+
+    //  unix timestamp after which the nomination can't be accepted, 0 for no expiry
+    pub pending_authority_expiry: i64,
+    pub pending_global_authority: Pubkey,
+    pub pending_global_authority_expiry: i64,
+    pub pending_team_wallet: Pubkey,
+    pub pending_team_wallet_expiry: i64,
+
+    //  merkle root of approved creators, all zero when unused
+    pub creator_merkle_root: [u8; 32],
+
+    //  can block and unblock wallets
+    pub compliance_authority: Pubkey,
+
+    //  team allocation vesting, seconds from launch
+    //  nothing is claimable before the cliff, then it releases linearly until the end
+    pub vesting_cliff: i64,
+    pub vesting_duration: i64,
+
+    //  bounds of the allocation table a creator can choose at launch, in basis points
+    //  all zero until the admin allows custom tables
+    pub min_curve_bps: u16,
+    pub max_creator_bps: u16,
+    pub max_team_bps: u16,
+    pub max_airdrop_bps: u16,
+
+    //  seconds after launch recipients can claim the airdrop, unclaimed tokens can be clawed back after
+    pub airdrop_claim_window: i64,
+
+    //  platform collection every spl token launch is verified into, default pubkey until created
+    pub collection_mint: Pubkey,
+
+    //  checked on launch and metadata updates
+    pub metadata_rules: MetadataRules,
+
+    //  launches appended to the registry so far
+    pub registry_count: u64,
+
+    //  per creator launch limits, zero disables them
+    pub launch_cooldown: i64,
+    pub launch_window: i64,
+    pub max_launches_per_window: u32,
+
+    //  charged to the creator at launch and held by the curve until it graduates
+    pub launch_fee_lamports: u64,
+    //  refund the fee to the creator on graduation instead of forwarding it to the team wallet
+    pub refund_launch_fee_on_graduation: bool,
+
+    //  share of the curve proceeds paid on graduation, in basis points
+    pub creator_graduation_reward_bps: u16,
+    pub completer_graduation_reward_bps: u16,
+
+    //  paid from the curve's SOL to whoever runs the migration
+    pub migration_crank_reward_lamports: u64,
+
+    //  SOL held back from a graduating curve to create the pool and its market
+    //  `market_cost_lamports` of it is paid to the caller, who creates the market
+    //  version 1 configs written before these fields get the defaults in `migrate_config`
+    pub migration_cost_lamports: u64,
+    pub market_cost_lamports: u64,
+
+    //  dex of launches which don't choose one
+    pub migration_target: MigrationTarget,
+
+    //  what happens to the lp tokens of a new pool
+    //  locked lp can be claimed by the team wallet after the duration, 0 locks it forever
+    pub lp_policy: LpPolicy,
+    pub lp_lock_duration: i64,
+
+    //  reserved for future fields
+    pub padding: [u8; 44],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AuthorityRole {
+    Authority,
+    GlobalAuthority,
+    TeamWallet,
+}
+
+impl Config {
+    //  2 stores the migration costs explicitly, 0 is a valid cost
+    pub const VERSION: u8 = 2;
+    pub const MAX_GRADUATION_REWARD_BPS: u16 = 1_000;
+    pub const MAX_CRANK_REWARD_LAMPORTS: u64 = 100_000_000;
+    pub const DEFAULT_MIGRATION_COST_LAMPORTS: u64 = 1_400_000_000;
+    pub const DEFAULT_MARKET_COST_LAMPORTS: u64 = 300_000_000;
+
+    //  creator is approved either by a whitelist pda or by a merkle proof
+    pub fn check_creator_whitelisted(
+        &self,
+        creator: &Pubkey,
+        whitelist: Option<&Whitelist>,
+        proof: &[[u8; 32]],
+    ) -> Result<()> {
+        if !self.whitelist_enabled {
+            return Ok(());
+        }
+
+        if let Some(whitelist) = whitelist {
+            if whitelist.creator == *creator {
+                return Ok(());
+            }
+        }
+
+        if self.creator_merkle_root != [0; 32]
+            && verify_merkle_proof(proof, &self.creator_merkle_root, &merkle_leaf(creator))
+        {
+            return Ok(());
+        }
+
+        err!(PumpfunError::NotWhiteList)
+    }
+
+    pub fn role_holder(&self, role: AuthorityRole) -> Pubkey {
+        match role {
+            AuthorityRole::Authority => self.authority,
+            AuthorityRole::GlobalAuthority => self.global_authority,
+            AuthorityRole::TeamWallet => self.team_wallet,
+        }
+    }
+
+    //  returns the pending nominee and its expiry for the role
+    pub fn pending_nomination(&self, role: AuthorityRole) -> (Pubkey, i64) {
+        match role {
+            AuthorityRole::Authority => (self.pending_authority, self.pending_authority_expiry),
+            AuthorityRole::GlobalAuthority => (
+                self.pending_global_authority,
+                self.pending_global_authority_expiry,
+            ),
+            AuthorityRole::TeamWallet => (self.pending_team_wallet, self.pending_team_wallet_expiry),
+        }
+    }
+
+    pub fn set_pending_nomination(&mut self, role: AuthorityRole, nominee: Pubkey, expiry: i64) {
+        let (pending, pending_expiry) = match role {
+            AuthorityRole::Authority => (
+                &mut self.pending_authority,
+                &mut self.pending_authority_expiry,
+            ),
+            AuthorityRole::GlobalAuthority => (
+                &mut self.pending_global_authority,
+                &mut self.pending_global_authority_expiry,
+            ),
+            AuthorityRole::TeamWallet => (
+                &mut self.pending_team_wallet,
+                &mut self.pending_team_wallet_expiry,
+            ),
+        };
+        *pending = nominee;
+        *pending_expiry = expiry;
+    }
+
+    //  moves the pending nominee into the role and clears the nomination
+    pub fn accept_nomination(&mut self, role: AuthorityRole) -> Pubkey {
+        let (nominee, _) = self.pending_nomination(role);
+        let previous = self.role_holder(role);
+        match role {
+            AuthorityRole::Authority => self.authority = nominee,
+            AuthorityRole::GlobalAuthority => self.global_authority = nominee,
+            AuthorityRole::TeamWallet => self.team_wallet = nominee,
+        }
+        self.set_pending_nomination(role, Pubkey::default(), 0);
+        previous
+    }
+
+    //  check every field before the config is stored
+    pub fn validate(&self) -> Result<()> {
+        for fee in [
+            self.platform_buy_fee,
+            self.platform_sell_fee,
+            self.platform_migration_fee,
+        ] {
+            if !(0_f64..=100_f64).contains(&fee) {
+                msg!("invalid fee {fee:?}, expected a percentage between 0 and 100");
+                return Err(InvalidFeePercent.into());
+            }
+        }
+
+        if !(self.init_bonding_curve > 0_f64 && self.init_bonding_curve <= 100_f64) {
+            msg!(
+                "invalid init bonding curve {:?}, expected a percentage above 0 and at most 100",
+                self.init_bonding_curve
+            );
+            return Err(InvalidInitBondingCurve.into());
+        }
+
+        if self.curve_limit == 0 {
+            return Err(InvalidCurveLimit.into());
+        }
+
+        self.lamport_amount_config.validate_bounds()?;
+        //  a curve launched at the limit is completed before its first buy
+        if let Some(max) = self.lamport_amount_config.upper_bound() {
+            if *max >= self.curve_limit {
+                msg!(
+                    "invalid curve limit {:?}, expected above the max initial reserve {:?}",
+                    self.curve_limit,
+                    max
+                );
+                return Err(CurveLimitBelowReserve.into());
+            }
+        }
+        self.token_supply_config.validate_bounds()?;
+        self.token_decimals_config.validate_bounds()?;
+
+        for bps in [
+            self.min_curve_bps,
+            self.max_creator_bps,
+            self.max_team_bps,
+            self.max_airdrop_bps,
+        ] {
+            if bps > AllocationTable::TOTAL_BPS {
+                msg!("invalid allocation limit {bps:?}, expected at most 10000 basis points");
+                return Err(InvalidAllocation.into());
+            }
+        }
+
+        self.metadata_rules.validate()?;
+
+        if self.creator_graduation_reward_bps as u32 + self.completer_graduation_reward_bps as u32
+            > Self::MAX_GRADUATION_REWARD_BPS as u32
+        {
+            msg!(
+                "invalid graduation rewards, expected at most {:?} basis points in total",
+                Self::MAX_GRADUATION_REWARD_BPS
+            );
+            return Err(InvalidGraduationReward.into());
+        }
+
+        if self.migration_crank_reward_lamports > Self::MAX_CRANK_REWARD_LAMPORTS {
+            msg!(
+                "invalid crank reward {:?}, expected at most {:?} lamports",
+                self.migration_crank_reward_lamports,
+                Self::MAX_CRANK_REWARD_LAMPORTS
+            );
+            return Err(InvalidCrankReward.into());
+        }
+
+        //  `transfer_fee` relies on the market cost being part of the migration cost
+        let (migration_cost, market_cost) =
+            (self.migration_cost_lamports, self.market_cost_lamports);
+        if market_cost > migration_cost
+            || migration_cost.saturating_add(self.migration_crank_reward_lamports)
+                >= self.curve_limit
+        {
+            msg!(
+                "invalid migration cost {:?} with market cost {:?}, curve limit {:?}",
+                migration_cost,
+                market_cost,
+                self.curve_limit
+            );
+            return Err(InvalidMigrationCost.into());
+        }
+
+        if self.launch_cooldown < 0 || self.launch_window < 0 {
+            msg!(
+                "invalid launch limits, cooldown {:?} window {:?}",
+                self.launch_cooldown,
+                self.launch_window
+            );
+            return Err(InvalidLaunchLimits.into());
+        }
+
+        //  a distributor with no claim window expires as it is created
+        if self.airdrop_claim_window < 0
+            || (self.max_airdrop_bps > 0 && self.airdrop_claim_window == 0)
+        {
+            msg!(
+                "invalid airdrop claim window {:?} with max airdrop {:?} basis points",
+                self.airdrop_claim_window,
+                self.max_airdrop_bps
+            );
+            return Err(InvalidAirdrop.into());
+        }
+
+        if self.lp_lock_duration < 0 {
+            msg!("invalid lp lock duration {:?}", self.lp_lock_duration);
+            return Err(InvalidLpLockDuration.into());
+        }
+
+        if self.vesting_cliff < 0 || self.vesting_duration < self.vesting_cliff {
+            msg!(
+                "invalid vesting schedule, cliff {:?} duration {:?}",
+                self.vesting_cliff,
+                self.vesting_duration
+            );
+            return Err(InvalidVestingSchedule.into());
+        }
+
+        Ok(())
+    }
+}
+
+//  limits on launch metadata, zero lengths fall back to the metaplex maximums
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct MetadataRules {
+    pub max_name_len: u8,
+    pub max_symbol_len: u8,
+    pub max_uri_len: u16,
+    pub ascii_symbol_only: bool,
+
+    //  uri should start with one of these, any uri when empty
+    pub uri_prefixes: Vec<String>,
+}
+
+impl MetadataRules {
+    pub const MAX_URI_PREFIXES: usize = 8;
+    pub const MAX_URI_PREFIX_LEN: usize = 32;
+
+    //  check the rules themselves
+    pub fn validate(&self) -> Result<()> {
+        if self.max_name_len as usize > MAX_NAME_LENGTH
+            || self.max_symbol_len as usize > MAX_SYMBOL_LENGTH
+            || self.max_uri_len as usize > MAX_URI_LENGTH
+        {
+            msg!("invalid metadata rules, lengths should not exceed the metaplex maximums");
+            return Err(InvalidMetadataRules.into());
+        }
+
+        if self.uri_prefixes.len() > Self::MAX_URI_PREFIXES
+            || self
+                .uri_prefixes
+                .iter()
+                .any(|prefix| prefix.is_empty() || prefix.len() > Self::MAX_URI_PREFIX_LEN)
+        {
+            msg!(
+                "invalid uri prefixes, expected at most {:?} non-empty prefixes of at most {:?} bytes",
+                Self::MAX_URI_PREFIXES,
+                Self::MAX_URI_PREFIX_LEN
+            );
+            return Err(InvalidMetadataRules.into());
+        }
+
+        Ok(())
+    }
+
+    fn max_len(configured: usize, maximum: usize) -> usize {
+        if configured == 0 {
+            maximum
+        } else {
+            configured
+        }
+    }
+
+    pub fn check_name(&self, name: &str) -> Result<()> {
+        let max = Self::max_len(self.max_name_len as usize, MAX_NAME_LENGTH);
+        if name.is_empty() || name.len() > max {
+            msg!("invalid name length {:?}, expected 1 to {max:?} bytes", name.len());
+            return Err(NameTooLong.into());
+        }
+        Ok(())
+    }
+
+    pub fn check_symbol(&self, symbol: &str) -> Result<()> {
+        let max = Self::max_len(self.max_symbol_len as usize, MAX_SYMBOL_LENGTH);
+        if symbol.is_empty() || symbol.len() > max {
+            msg!("invalid symbol length {:?}, expected 1 to {max:?} bytes", symbol.len());
+            return Err(SymbolTooLong.into());
+        }
+        if self.ascii_symbol_only
+            && !symbol.bytes().all(|byte| byte.is_ascii_graphic())
+        {
+            return Err(SymbolNotAscii.into());
+        }
+        Ok(())
+    }
+
+    pub fn check_uri(&self, uri: &str) -> Result<()> {
+        let max = Self::max_len(self.max_uri_len as usize, MAX_URI_LENGTH);
+        if uri.len() > max {
+            msg!("invalid uri length {:?}, expected at most {max:?} bytes", uri.len());
+            return Err(UriTooLong.into());
+        }
+        if !self.uri_prefixes.is_empty()
+            && !self
+                .uri_prefixes
+                .iter()
+                .any(|prefix| uri.starts_with(prefix.as_str()))
+        {
+            return Err(UriPrefixNotAllowed.into());
+        }
+        Ok(())
+    }
+
+    pub fn check(&self, name: &str, symbol: &str, uri: &str) -> Result<()> {
+        self.check_name(name)?;
+        self.check_symbol(symbol)?;
+        self.check_uri(uri)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AmountConfig<T: PartialEq + PartialOrd + Debug> {
+    Range { min: Option<T>, max: Option<T> },
+    Enum(Vec<T>),
+}
+
+impl<T: PartialEq + PartialOrd + Debug> AmountConfig<T> {
+    //  check the config itself is satisfiable
+    pub fn validate_bounds(&self) -> Result<()> {
+        match self {
+            Self::Range {
+                min: Some(min),
+                max: Some(max),
+            } if min > max => {
+                msg!("invalid range, min {min:?} is greater than max {max:?}");
+                Err(InvalidAmountConfig.into())
+            }
+            Self::Enum(options) if options.is_empty() => {
+                msg!("invalid enum, expected at least one option");
+                Err(InvalidAmountConfig.into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    //  largest accepted value, none for an open range
+    pub fn upper_bound(&self) -> Option<&T> {
+        match self {
+            Self::Range { max, .. } => max.as_ref(),
+            Self::Enum(options) => options
+                .iter()
+                .reduce(|max, option| if option > max { option } else { max }),
+        }
+    }
+
+    pub fn validate(&self, value: &T) -> Result<()> {
+        match self {
+            Self::Range { min, max } => {
+                if let Some(min) = min {
+                    if value < min {
+                        msg!("value {value:?} too small, expected at least {min:?}");
+                        return Err(ValueTooSmall.into());
+                    }
+                }
+                if let Some(max) = max {
+                    if value > max {
+                        msg!("value {value:?} too large, expected at most {max:?}");
+                        return Err(ValueTooLarge.into());
+                    }
+                }
+
+                Ok(())
+            }
+            Self::Enum(options) => {
+                if options.contains(value) {
+                    Ok(())
+                } else {
+                    msg!("invalid value {value:?}, expected one of: {options:?}");
+                    Err(ValueInvalid.into())
+                }
+            }
+        }
+    }
+}
+
+//  split of the token supply chosen at launch, in basis points
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocationTable {
+    pub creator_bps: u16,
+    pub team_bps: u16,
+    pub airdrop_bps: u16,
+    pub curve_bps: u16,
+}
+
+impl AllocationTable {
+    pub const TOTAL_BPS: u16 = 10_000;
+
+    //  legacy split, curve share from config and the rest to the team
+    pub fn from_config(global_config: &Config) -> Self {
+        let curve_bps = (global_config.init_bonding_curve * 100_f64) as u16;
+        Self {
+            creator_bps: 0,
+            team_bps: Self::TOTAL_BPS - curve_bps,
+            airdrop_bps: 0,
+            curve_bps,
+        }
+    }
+
+    pub fn validate(&self, global_config: &Config) -> Result<()> {
+        let total = self.creator_bps as u32
+            + self.team_bps as u32
+            + self.airdrop_bps as u32
+            + self.curve_bps as u32;
+        if total != Self::TOTAL_BPS as u32 {
+            msg!("invalid allocation table, expected 10000 basis points in total, got {total:?}");
+            return Err(InvalidAllocation.into());
+        }
+
+        if self.curve_bps == 0
+            || self.curve_bps < global_config.min_curve_bps
+            || self.creator_bps > global_config.max_creator_bps
+            || self.team_bps > global_config.max_team_bps
+            || self.airdrop_bps > global_config.max_airdrop_bps
+        {
+            msg!("allocation table {self:?} is out of the configured bounds");
+            return Err(InvalidAllocation.into());
+        }
+
+        Ok(())
+    }
+
+    //  token amount of a share, rounding down
+    pub fn amount_of(token_supply: u64, bps: u16) -> u64 {
+        (token_supply as u128 * bps as u128 / Self::TOTAL_BPS as u128) as u64
+    }
+}
+
+//  merkle airdrop of the airdrop allocation chosen at launch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct AirdropParams {
+    //  root of a tree over (index, recipient, amount) leaves
+    pub merkle_root: [u8; 32],
+    pub num_recipients: u32,
+}
+
+//  holds the airdrop allocation of a launch until recipients claim it
+#[account]
+#[derive(Debug)]
+pub struct Distributor {
+    pub version: u8,
+    pub mint: Pubkey,
+    pub merkle_root: [u8; 32],
+
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub num_recipients: u32,
+    pub num_claimed: u32,
+
+    //  unix timestamp after which claims stop and the admin can clawback
+    pub expiry_ts: i64,
+    pub clawed_back: bool,
+    pub bump: u8,
+
+    //  reserved for future fields
+    pub padding: [u8; 32],
+
+    //  one bit per leaf index
+    pub claimed_bitmap: Vec<u8>,
+}
+
+impl Distributor {
+    pub const VERSION: u8 = 1;
+    pub const MAX_RECIPIENTS: u32 = 65_536;
+
+    pub fn space(num_recipients: u32) -> usize {
+        8 + 1 + 32 + 32 + 8 + 8 + 4 + 4 + 8 + 1 + 1 + 32 + 4 + Self::bitmap_len(num_recipients)
+    }
+
+    pub fn bitmap_len(num_recipients: u32) -> usize {
+        num_recipients.div_ceil(8) as usize
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claimed_bitmap[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        self.claimed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+    }
+}
+
+//  token-2022 transfer fee extension chosen at launch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct TransferFeeParams {
+    pub basis_points: u16,
+    pub maximum_fee: u64,
+}
+
+impl TransferFeeParams {
+    //  same cap as listed mints, every swap and the pool pay it
+    pub fn validate(&self) -> Result<()> {
+        if self.basis_points > MAX_TRANSFER_FEE_BPS {
+            msg!(
+                "transfer fee {:?} exceeds {:?} basis points",
+                self.basis_points,
+                MAX_TRANSFER_FEE_BPS
+            );
+            return Err(TransferFeeTooHigh.into());
+        }
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BondingCurve {
+    //  layout version, see `BondingCurve::VERSION`
+    pub version: u8,
+
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+
+    pub init_lamport: u64,
+
+    pub reserve_lamport: u64,
+    pub reserve_token: u64,
+
+    pub is_completed: bool,
+
+    //  quote asset of the curve, default pubkey for SOL
+    //  `init_lamport` and `reserve_lamport` are in base units of this mint
+    pub quote_mint: Pubkey,
+    pub quote_decimals: u8,
+
+    //  supply split chosen at launch, all zero for curves created before it was recorded
+    pub allocation: AllocationTable,
+
+    //  creator can choose to make the metadata immutable when the curve migrates
+    pub lock_metadata_on_migration: bool,
+
+    //  position in the launch registry, curves created before the registry aren't registered
+    pub is_registered: bool,
+    pub registry_index: u64,
+
+    //  launch fee escrowed in this account and its policy at launch time
+    pub launch_fee_escrowed: u64,
+    pub refund_launch_fee: bool,
+
+    //  set when the curve completes, rewards are paid out of the proceeds at migration
+    pub completer: Pubkey,
+    pub creator_reward: u64,
+    pub completer_reward: u64,
+
+    //  migration runs in two steps, fees are settled first and the pool is created after
+    //  `pool_pc_amount` and `crank_reward` are held back by the first step for the second
+    //  `crank_reward` includes the market cost of targets with a market, paid to the caller of `migrate`
+    pub is_fee_settled: bool,
+    pub is_migrated: bool,
+    pub pool_pc_amount: u64,
+    pub crank_reward: u64,
+
+    //  dex chosen at launch
+    pub migration_target: MigrationTarget,
+
+    //  reserved for future fields
+    pub padding: [u8; 128],
+}
+
+impl BondingCurve {
+    //  2 restores the reserved space used up by the fields of version 1
+    pub const VERSION: u8 = 2;
+    //  version 1 is the same layout with 1 byte of padding instead of 128
+    pub const V1_SPACE: usize = Self::INIT_SPACE - 127;
+
+    pub fn is_sol_quote(&self) -> bool {
+        self.quote_mint == Pubkey::default()
+    }
+
+    //  pc side of the raydium pool, wsol for SOL curves
+    pub fn pc_mint(&self) -> Pubkey {
+        if self.is_sol_quote() {
+            spl_token::native_mint::ID
+        } else {
+            self.quote_mint
+        }
+    }
+
+    pub fn quote_decimals(&self) -> u8 {
+        if self.is_sol_quote() {
+            LAMPORT_DECIMALS
+        } else {
+            self.quote_decimals
+        }
+    }
+
+    //  lamports or quote tokens needed to complete the curve
+    pub fn curve_limit(
+        &self,
+        global_config: &Config,
+        quote_config: Option<&QuoteConfig>,
+    ) -> Result<u64> {
+        if self.is_sol_quote() {
+            return Ok(global_config.curve_limit);
+        }
+        match quote_config {
+            Some(quote_config) if quote_config.mint == self.quote_mint => {
+                Ok(quote_config.curve_limit)
+            }
+            _ => err!(PumpfunError::InvalidQuoteMint),
+        }
+    }
+}
+
+//  dex a completed curve migrates to, see `crate::migration` for each target
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq,
+)]
+pub enum MigrationTarget {
+    //  needs an openbook market created by the caller
+    #[default]
+    RaydiumAmmV4,
+    RaydiumCpmm,
+    MeteoraDamm,
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq, Eq,
+)]
+pub enum LpPolicy {
+    #[default]
+    Burn,
+    //  held by the `LpLock` of the curve
+    Lock,
+}
+
+//  lp tokens of a migrated pool, held by the ata of this pda until they unlock
+#[account]
+#[derive(InitSpace, Debug, Default)]
+pub struct LpLock {
+    pub version: u8,
+    pub mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub beneficiary: Pubkey,
+
+    pub amount: u64,
+    //  unix timestamp, 0 when locked forever
+    pub unlock_ts: i64,
+
+    pub bump: u8,
+
+    //  reserved for future fields
+    pub padding: [u8; 32],
+}
+
+impl LpLock {
+    pub const SEED_PREFIX: &'static str = "lp-lock";
+    pub const VERSION: u8 = 1;
+
+    pub fn is_unlocked(&self, now: i64) -> bool {
+        self.unlock_ts != 0 && now >= self.unlock_ts
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchStatus {
+    Trading,
+    Completed,
+    Migrated,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug)]
+pub struct RegistryEntry {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+    pub launch_slot: u64,
+    pub status: LaunchStatus,
+}
+
+//  fixed size page of launches in creation order
+#[account]
+#[derive(InitSpace)]
+pub struct RegistryPage {
+    pub version: u8,
+    pub index: u32,
+    #[max_len(32)]
+    pub entries: Vec<RegistryEntry>,
+}
+
+impl RegistryPage {
+    pub const SEED_PREFIX: &'static str = "registry";
+    pub const VERSION: u8 = 1;
+    pub const PAGE_SIZE: u64 = 32;
+
+    pub fn page_of(registry_index: u64) -> u32 {
+        (registry_index / Self::PAGE_SIZE) as u32
+    }
+
+    //  append a launch and record its position on the curve
+    pub fn register(
+        &mut self,
+        global_config: &mut Config,
+        bonding_curve: &mut BondingCurve,
+        bonding_curve_key: Pubkey,
+    ) -> Result<()> {
+        let registry_index = global_config.registry_count;
+
+        self.version = Self::VERSION;
+        self.index = Self::page_of(registry_index);
+        self.entries.push(RegistryEntry {
+            mint: bonding_curve.token_mint,
+            bonding_curve: bonding_curve_key,
+            creator: bonding_curve.creator,
+            launch_slot: Clock::get()?.slot,
+            status: LaunchStatus::Trading,
+        });
+
+        bonding_curve.is_registered = true;
+        bonding_curve.registry_index = registry_index;
+        global_config.registry_count += 1;
+
+        Ok(())
+    }
+
+    pub fn set_status(
+        page: Option<&mut RegistryPage>,
+        bonding_curve: &BondingCurve,
+        status: LaunchStatus,
+    ) -> Result<()> {
+        if !bonding_curve.is_registered {
+            return Ok(());
+        }
+        let Some(page) = page else {
+            return err!(PumpfunError::MissingRegistryPage);
+        };
+        if page.index != Self::page_of(bonding_curve.registry_index) {
+            msg!(
+                "registry index {:?} isn't on page {:?}",
+                bonding_curve.registry_index,
+                page.index
+            );
+            return err!(PumpfunError::InvalidRegistryPage);
+        }
+        let entry = page
+            .entries
+            .get_mut((bonding_curve.registry_index % Self::PAGE_SIZE) as usize)
+            .filter(|entry| entry.mint == bonding_curve.token_mint)
+            .ok_or(PumpfunError::InvalidRegistryPage)?;
+        entry.status = status;
+        Ok(())
+    }
+}
+
+//  admin approved spl quote asset
+#[account]
+#[derive(InitSpace)]
+pub struct QuoteConfig {
+    pub version: u8,
+    pub mint: Pubkey,
+    pub enabled: bool,
+
+    //  quote tokens to complete the curve
+    pub curve_limit: u64,
+
+    //  bounds of the initial virtual quote reserve
+    pub min_init_reserve: u64,
+    pub max_init_reserve: u64,
+
+    //  reserved for future fields
+    pub padding: [u8; 64],
+}
+
+impl QuoteConfig {
+    pub const VERSION: u8 = 1;
+
+    pub fn validate(&self) -> Result<()> {
+        if self.curve_limit == 0 {
+            return Err(InvalidCurveLimit.into());
+        }
+        if self.max_init_reserve >= self.curve_limit {
+            msg!(
+                "invalid curve limit {:?}, expected above the max initial reserve {:?}",
+                self.curve_limit,
+                self.max_init_reserve
+            );
+            return Err(CurveLimitBelowReserve.into());
+        }
+        AmountConfig::Range {
+            min: Some(self.min_init_reserve),
+            max: Some(self.max_init_reserve),
+        }
+        .validate_bounds()
+    }
+
+    pub fn validate_init_reserve(&self, init_reserve: u64) -> Result<()> {
+        AmountConfig::Range {
+            min: Some(self.min_init_reserve),
+            max: Some(self.max_init_reserve),
+        }
+        .validate(&init_reserve)
+    }
+}
+
+//  accounts moving the quote asset of an spl quote curve
+pub struct QuoteAccounts<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub user_ata: AccountInfo<'info>,
+    pub vault_ata: AccountInfo<'info>,
+    pub team_wallet_ata: AccountInfo<'info>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+//  layouts deployed before the version field was added
+//  only used by the migrate instructions to upgrade accounts in place
+
+#[derive(AnchorDeserialize)]
+pub struct ConfigV0 {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub team_wallet: Pubkey,
+    pub init_bonding_curve: f64,
+    pub platform_buy_fee: f64,
     pub platform_sell_fee: f64,
     pub platform_migration_fee: f64,
-
-    pub curve_limit: u64, //  lamports to complete te bonding curve
-
+    pub curve_limit: u64,
     pub lamport_amount_config: AmountConfig<u64>,
     pub token_supply_config: AmountConfig<u64>,
     pub token_decimals_config: AmountConfig<u8>,
-
     pub initialized: bool,
-    pub global_authority: Pubkey,    // can update settings
-
+    pub global_authority: Pubkey,
     pub whitelist_enabled: bool,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
-pub enum AmountConfig<T: PartialEq + PartialOrd + Debug> {
-    Range { min: Option<T>, max: Option<T> },
-    Enum(Vec<T>),
-}
-
-impl<T: PartialEq + PartialOrd + Debug> AmountConfig<T> {
-    pub fn validate(&self, value: &T) -> Result<()> {
-        match self {
-            Self::Range { min, max } => {
-                if let Some(min) = min {
-                    if value < min {
-                        msg!("value {value:?} too small, expected at least {min:?}");
-                        return Err(ValueTooSmall.into());
-                    }
-                }
-                if let Some(max) = max {
-                    if value > max {
-                        msg!("value {value:?} too large, expected at most {max:?}");
-                        return Err(ValueTooLarge.into());
-                    }
-                }
-
-                Ok(())
-            }
-            Self::Enum(options) => {
-                if options.contains(value) {
-                    Ok(())
-                } else {
-                    msg!("invalid value {value:?}, expected one of: {options:?}");
-                    Err(ValueInvalid.into())
-                }
-            }
+impl From<ConfigV0> for Config {
+    fn from(config: ConfigV0) -> Self {
+        Self {
+            version: Config::VERSION,
+            authority: config.authority,
+            pending_authority: config.pending_authority,
+            team_wallet: config.team_wallet,
+            init_bonding_curve: config.init_bonding_curve,
+            platform_buy_fee: config.platform_buy_fee,
+            platform_sell_fee: config.platform_sell_fee,
+            platform_migration_fee: config.platform_migration_fee,
+            curve_limit: config.curve_limit,
+            lamport_amount_config: config.lamport_amount_config,
+            token_supply_config: config.token_supply_config,
+            token_decimals_config: config.token_decimals_config,
+            initialized: config.initialized,
+            global_authority: config.global_authority,
+            whitelist_enabled: config.whitelist_enabled,
+            pending_authority_expiry: 0,
+            pending_global_authority: Pubkey::default(),
+            pending_global_authority_expiry: 0,
+            pending_team_wallet: Pubkey::default(),
+            pending_team_wallet_expiry: 0,
+            creator_merkle_root: [0; 32],
+            compliance_authority: Pubkey::default(),
+            vesting_cliff: 0,
+            vesting_duration: 0,
+            min_curve_bps: 0,
+            max_creator_bps: 0,
+            max_team_bps: 0,
+            max_airdrop_bps: 0,
+            airdrop_claim_window: 0,
+            collection_mint: Pubkey::default(),
+            metadata_rules: MetadataRules::default(),
+            registry_count: 0,
+            launch_cooldown: 0,
+            launch_window: 0,
+            max_launches_per_window: 0,
+            launch_fee_lamports: 0,
+            refund_launch_fee_on_graduation: false,
+            creator_graduation_reward_bps: 0,
+            completer_graduation_reward_bps: 0,
+            migration_crank_reward_lamports: 0,
+            //  costs hard-coded before they were configurable
+            migration_cost_lamports: Config::DEFAULT_MIGRATION_COST_LAMPORTS,
+            market_cost_lamports: Config::DEFAULT_MARKET_COST_LAMPORTS,
+            migration_target: MigrationTarget::RaydiumAmmV4,
+            lp_policy: LpPolicy::Burn,
+            lp_lock_duration: 0,
+            padding: [0; 44],
         }
     }
 }
 
-#[account]
-pub struct BondingCurve {
+#[derive(AnchorDeserialize)]
+pub struct BondingCurveV0 {
     pub token_mint: Pubkey,
     pub creator: Pubkey,
-
     pub init_lamport: u64,
-
     pub reserve_lamport: u64,
     pub reserve_token: u64,
-
     pub is_completed: bool,
 }
+
+impl From<BondingCurveV0> for BondingCurve {
+    fn from(curve: BondingCurveV0) -> Self {
+        Self {
+            version: BondingCurve::VERSION,
+            token_mint: curve.token_mint,
+            creator: curve.creator,
+            init_lamport: curve.init_lamport,
+            reserve_lamport: curve.reserve_lamport,
+            reserve_token: curve.reserve_token,
+            is_completed: curve.is_completed,
+            quote_mint: Pubkey::default(),
+            quote_decimals: LAMPORT_DECIMALS,
+            allocation: AllocationTable::default(),
+            lock_metadata_on_migration: false,
+            is_registered: false,
+            registry_index: 0,
+            launch_fee_escrowed: 0,
+            refund_launch_fee: false,
+            completer: Pubkey::default(),
+            creator_reward: 0,
+            completer_reward: 0,
+            is_fee_settled: false,
+            is_migrated: false,
+            pool_pc_amount: 0,
+            crank_reward: 0,
+            migration_target: MigrationTarget::RaydiumAmmV4,
+            padding: [0; 128],
+        }
+    }
+}
+
+impl BondingCurve {
+    //  version 1 data without the discriminator, the missing padding is zeroed
+    pub fn from_v1(data: &[u8]) -> Result<Self> {
+        if data.len() < Self::V1_SPACE || data[0] != 1 {
+            return err!(PumpfunError::UnsupportedLayoutVersion);
+        }
+        let mut layout = data[..Self::V1_SPACE].to_vec();
+        layout.resize(Self::INIT_SPACE, 0);
+
+        let mut bonding_curve = Self::deserialize(&mut &layout[..])?;
+        bonding_curve.version = Self::VERSION;
+        Ok(bonding_curve)
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct WhitelistV0 {
+    pub creator: Pubkey,
+}
+
+impl From<WhitelistV0> for Whitelist {
+    fn from(whitelist: WhitelistV0) -> Self {
+        Self {
+            version: Whitelist::VERSION,
+            creator: whitelist.creator,
+            padding: [0; 32],
+        }
+    }
+}
+
+//  every V0 layout starts with a pubkey the caller already knows,
+//  while versioned layouts start with the version byte
+pub fn is_v0_layout(data: &[u8], first_key: &Pubkey) -> bool {
+    data.len() >= 8 + 32 && data[8..40] == first_key.to_bytes()
+}
 pub trait BondingCurveAccount<'info> {
     // Updates the token reserves in the liquidity pool
     fn update_reserves(
         &mut self,
-        global_config: &Account<'info, Config>,
+        curve_limit: u64,
         reserve_one: u64,
         reserve_two: u64,
     ) -> Result<bool>;
@@ -111,7 +1241,8 @@ pub trait BondingCurveAccount<'info> {
     fn swap(
         &mut self,
         global_config: &Account<'info, Config>,
-        token_mint: &Account<'info, Mint>,
+        curve_limit: u64,
+        token_mint: &InterfaceAccount<'info, Mint>,
         global_ata: &mut AccountInfo<'info>,
         user_ata: &mut AccountInfo<'info>,
         source: &mut AccountInfo<'info>,
@@ -124,14 +1255,16 @@ pub trait BondingCurveAccount<'info> {
         user: &Signer<'info>,
         signer: &[&[&[u8]]],
 
-        token_program: &Program<'info, Token>,
+        token_program: &Interface<'info, TokenInterface>,
         system_program: &Program<'info, System>,
+
+        quote: Option<QuoteAccounts<'_, 'info>>,
     ) -> Result<u64>;
 
     fn simulate_swap(
         &self,
         global_config: &Account<'info, Config>,
-        token_mint: &Account<'info, Mint>,
+        token_mint: &InterfaceAccount<'info, Mint>,
         amount: u64,
         direction: u8,
     ) -> Result<u64>;
@@ -140,23 +1273,62 @@ pub trait BondingCurveAccount<'info> {
         &self,
         amount: u64,
         token_one_decimals: u8,
+        quote_decimals: u8,
         direction: u8,
         platform_sell_fee: f64,
         platform_buy_fee: f64,
     ) -> Result<(u64, u64)>;
+
+    //  pay out the escrowed launch fee once the curve graduates
+    fn release_launch_fee(
+        &mut self,
+        team_wallet: &AccountInfo<'info>,
+        creator: &AccountInfo<'info>,
+    ) -> Result<()>;
 }
 
 impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
+    fn release_launch_fee(
+        &mut self,
+        team_wallet: &AccountInfo<'info>,
+        creator: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let amount = self.launch_fee_escrowed;
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let recipient = if self.refund_launch_fee {
+            creator
+        } else {
+            team_wallet
+        };
+
+        //  the curve is owned by this program, so lamports move without a cpi
+        self.launch_fee_escrowed = 0;
+        self.sub_lamports(amount)?;
+        recipient.add_lamports(amount)?;
+
+        emit!(LaunchFeeReleased {
+            mint: self.token_mint,
+            recipient: recipient.key(),
+            amount,
+            refunded: self.refund_launch_fee,
+        });
+
+        Ok(())
+    }
+
     fn update_reserves(
         &mut self,
-        global_config: &Account<'info, Config>,
+        curve_limit: u64,
         reserve_token: u64,
         reserve_lamport: u64,
     ) -> Result<bool> {
         self.reserve_token = reserve_token;
         self.reserve_lamport = reserve_lamport;
 
-        if reserve_lamport >= global_config.curve_limit {
+        if reserve_lamport >= curve_limit {
             msg!("curve is completed");
             self.is_completed = true;
             return Ok(true);
@@ -168,8 +1340,9 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
     fn swap(
         &mut self,
         global_config: &Account<'info, Config>,
+        curve_limit: u64,
 
-        token_mint: &Account<'info, Mint>,
+        token_mint: &InterfaceAccount<'info, Mint>,
         global_ata: &mut AccountInfo<'info>,
         user_ata: &mut AccountInfo<'info>,
 
@@ -184,8 +1357,10 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
         user: &Signer<'info>,
         signer: &[&[&[u8]]],
 
-        token_program: &Program<'info, Token>,
+        token_program: &Interface<'info, TokenInterface>,
         system_program: &Program<'info, System>,
+
+        quote: Option<QuoteAccounts<'_, 'info>>,
     ) -> Result<u64> {
         if amount <= 0 {
             return err!(PumpfunError::InvalidAmount);
@@ -195,7 +1370,11 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
         let amount = if direction == 1 {
             amount
         } else {
-            amount.min(global_config.curve_limit - self.reserve_lamport)
+            //  the limit can be lowered under a live curve by the admin
+            let remaining = curve_limit
+                .checked_sub(self.reserve_lamport)
+                .ok_or(PumpfunError::CurveLimitBelowReserve)?;
+            amount.min(remaining)
         };
 
         msg!("Mint: {:?} ", token_mint.key());
@@ -212,19 +1391,28 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
         let (adjusted_amount, amount_out) = self.cal_amount_out(
             amount,
             token_mint.decimals,
+            self.quote_decimals(),
             direction,
             global_config.platform_sell_fee,
             global_config.platform_buy_fee,
         )?;
 
-        if amount_out < minimum_receive_amount {
+        //  token-2022 transfer fee is withheld from the tokens the receiver gets
+        let received_amount = if direction == 1 {
+            amount_out
+        } else {
+            amount_out - transfer_fee_of(token_mint, amount_out)?
+        };
+        if received_amount < minimum_receive_amount {
             return Err(PumpfunError::ReturnAmountTooSmall.into());
         }
 
         if direction == 1 {
+            let withheld_fee = transfer_fee_of(token_mint, adjusted_amount)?;
             let new_reserves_one = self
                 .reserve_token
                 .checked_add(amount)
+                .and_then(|reserve| reserve.checked_sub(withheld_fee))
                 .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
 
             let new_reserves_two = self
@@ -232,7 +1420,7 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                 .checked_sub(amount_out)
                 .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
 
-            self.update_reserves(global_config, new_reserves_one, new_reserves_two)?;
+            self.update_reserves(curve_limit, new_reserves_one, new_reserves_two)?;
 
             msg! {"Reserves: {:?} {:?}", new_reserves_one, new_reserves_two};
 
@@ -240,17 +1428,29 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                 user_ata.clone(),
                 &user,
                 global_ata.clone(),
+                token_mint,
                 &token_program,
                 adjusted_amount,
             )?;
 
-            sol_transfer_with_signer(
-                source.clone(),
-                user.to_account_info(),
-                &system_program,
-                signer,
-                amount_out,
-            )?;
+            match &quote {
+                Some(quote) => token_transfer_with_signer(
+                    quote.vault_ata.clone(),
+                    source.clone(),
+                    quote.user_ata.clone(),
+                    quote.mint,
+                    quote.token_program,
+                    signer,
+                    amount_out,
+                )?,
+                None => sol_transfer_with_signer(
+                    source.clone(),
+                    user.to_account_info(),
+                    &system_program,
+                    signer,
+                    amount_out,
+                )?,
+            }
 
             //  transfer fee to team wallet
             let fee_amount = amount - adjusted_amount;
@@ -261,6 +1461,7 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                 user_ata.clone(),
                 &user,
                 team_wallet_ata.clone(),
+                token_mint,
                 &token_program,
                 fee_amount,
             )?;
@@ -276,13 +1477,26 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                 .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
 
             let is_completed =
-                self.update_reserves(global_config, new_reserves_one, new_reserves_two)?;
+                self.update_reserves(curve_limit, new_reserves_one, new_reserves_two)?;
 
             if is_completed == true {
+                //  lock in graduation rewards so config changes can't move them before migration
+                let proceeds = new_reserves_two
+                    .checked_sub(self.init_lamport)
+                    .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+                self.completer = user.key();
+                self.creator_reward =
+                    bps_of(proceeds, global_config.creator_graduation_reward_bps);
+                self.completer_reward =
+                    bps_of(proceeds, global_config.completer_graduation_reward_bps);
+
                 emit!(CompleteEvent {
                     user: user.key(),
                     mint: token_mint.key(),
-                    bonding_curve: self.key()
+                    bonding_curve: self.key(),
+                    creator: self.creator,
+                    creator_reward: self.creator_reward,
+                    completer_reward: self.completer_reward,
                 });
             }
 
@@ -292,18 +1506,40 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                 global_ata.clone(),
                 source.clone(),
                 user_ata.clone(),
+                token_mint,
                 &token_program,
                 signer,
                 amount_out,
             )?;
 
-            sol_transfer_from_user(&user, source.clone(), &system_program, amount)?;
-
             //  transfer fee to team wallet
             let fee_amount = amount - adjusted_amount;
             msg! {"fee: {:?}", fee_amount}
 
-            sol_transfer_from_user(&user, team_wallet.clone(), &system_program, fee_amount)?;
+            match &quote {
+                Some(quote) => {
+                    token_transfer_user(
+                        quote.user_ata.clone(),
+                        user,
+                        quote.vault_ata.clone(),
+                        quote.mint,
+                        quote.token_program,
+                        amount,
+                    )?;
+                    token_transfer_user(
+                        quote.user_ata.clone(),
+                        user,
+                        quote.team_wallet_ata.clone(),
+                        quote.mint,
+                        quote.token_program,
+                        fee_amount,
+                    )?;
+                }
+                None => {
+                    sol_transfer_from_user(&user, source.clone(), &system_program, amount)?;
+                    sol_transfer_from_user(&user, team_wallet.clone(), &system_program, fee_amount)?;
+                }
+            }
         }
         Ok(amount_out)
     }
@@ -311,7 +1547,7 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
     fn simulate_swap(
         &self,
         global_config: &Account<'info, Config>,
-        token_mint: &Account<'info, Mint>,
+        token_mint: &InterfaceAccount<'info, Mint>,
         amount: u64,
         direction: u8,
     ) -> Result<u64> {
@@ -323,6 +1559,7 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
             .cal_amount_out(
                 amount,
                 token_mint.decimals,
+                self.quote_decimals(),
                 direction,
                 global_config.platform_sell_fee,
                 global_config.platform_buy_fee,
@@ -334,6 +1571,7 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
         &self,
         amount: u64,
         token_one_decimals: u8,
+        quote_decimals: u8,
         direction: u8,
         platform_sell_fee: f64,
         platform_buy_fee: f64,
@@ -376,9 +1614,9 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
             // dy = y / ((x + dx) / dx)
             // dx = ydx / (x + dx)
             let amount_out_in_float =
-                convert_to_float(self.reserve_lamport, LAMPORT_DECIMALS).div(div_amt);
+                convert_to_float(self.reserve_lamport, quote_decimals).div(div_amt);
 
-            amount_out = convert_from_float(amount_out_in_float, LAMPORT_DECIMALS);
+            amount_out = convert_from_float(amount_out_in_float, quote_decimals);
         } else {
             // buy, sol for token
             // y + dy sol
@@ -388,8 +1626,8 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                 .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
 
             // (y + dy) / dy
-            let div_amt = convert_to_float(denominator_sum, LAMPORT_DECIMALS)
-                .div(convert_to_float(adjusted_amount, LAMPORT_DECIMALS));
+            let div_amt = convert_to_float(denominator_sum, quote_decimals)
+                .div(convert_to_float(adjusted_amount, quote_decimals));
 
             // dx = x / ((y + dy) / dy)
             // dx = xdy / (y + dy)
@@ -858,4 +2096,623 @@ impl<'info> TokenLaunchAccount<'info> for Account<'info, TokenLaunch> {
         )?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> Config {
+        Config::from(ConfigV0 {
+            authority: Pubkey::new_unique(),
+            pending_authority: Pubkey::default(),
+            team_wallet: Pubkey::new_unique(),
+            init_bonding_curve: 80_f64,
+            platform_buy_fee: 1_f64,
+            platform_sell_fee: 1_f64,
+            platform_migration_fee: 1_f64,
+            curve_limit: 85_000_000_000,
+            lamport_amount_config: AmountConfig::Range {
+                min: None,
+                max: None,
+            },
+            token_supply_config: AmountConfig::Range {
+                min: None,
+                max: None,
+            },
+            token_decimals_config: AmountConfig::Range {
+                min: None,
+                max: None,
+            },
+            initialized: true,
+            global_authority: Pubkey::new_unique(),
+            whitelist_enabled: false,
+        })
+    }
+
+    fn assert_error<T: Debug>(result: Result<T>, expected: PumpfunError) {
+        match result {
+            Err(Error::AnchorError(error)) => {
+                assert_eq!(error.error_code_number, u32::from(expected))
+            }
+            other => panic!("expected {expected:?}, got {other:?}"),
+        }
+    }
+
+    fn allocation_config() -> Config {
+        let mut config = test_config();
+        config.min_curve_bps = 5_000;
+        config.max_creator_bps = 2_000;
+        config.max_team_bps = 2_000;
+        config.max_airdrop_bps = 1_000;
+        config
+    }
+
+    #[test]
+    fn allocation_table_accepts_exact_total() {
+        let table = AllocationTable {
+            creator_bps: 2_000,
+            team_bps: 1_500,
+            airdrop_bps: 500,
+            curve_bps: 6_000,
+        };
+        assert!(table.validate(&allocation_config()).is_ok());
+    }
+
+    #[test]
+    fn allocation_table_rejects_total_over_10000() {
+        let table = AllocationTable {
+            creator_bps: 2_000,
+            team_bps: 1_500,
+            airdrop_bps: 500,
+            curve_bps: 6_001,
+        };
+        assert_error(
+            table.validate(&allocation_config()),
+            PumpfunError::InvalidAllocation,
+        );
+    }
+
+    #[test]
+    fn allocation_table_rejects_total_under_10000() {
+        let table = AllocationTable {
+            creator_bps: 2_000,
+            team_bps: 1_500,
+            airdrop_bps: 500,
+            curve_bps: 5_999,
+        };
+        assert_error(
+            table.validate(&allocation_config()),
+            PumpfunError::InvalidAllocation,
+        );
+    }
+
+    #[test]
+    fn allocation_table_rejects_shares_out_of_bounds() {
+        let table = AllocationTable {
+            creator_bps: 2_001,
+            team_bps: 1_500,
+            airdrop_bps: 500,
+            curve_bps: 5_999,
+        };
+        assert_error(
+            table.validate(&allocation_config()),
+            PumpfunError::InvalidAllocation,
+        );
+    }
+
+    #[test]
+    fn allocation_amount_of_rounds_down() {
+        assert_eq!(
+            AllocationTable::amount_of(1_000_000_001, 3_333),
+            333_300_000
+        );
+        assert_eq!(AllocationTable::amount_of(9, 5_000), 4);
+        assert_eq!(AllocationTable::amount_of(1, 9_999), 0);
+        assert_eq!(AllocationTable::amount_of(u64::MAX, 10_000), u64::MAX);
+    }
+
+    fn test_vesting() -> Vesting {
+        Vesting {
+            total_amount: 1_000,
+            start_ts: 100,
+            cliff_ts: 200,
+            end_ts: 1_100,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn vesting_before_cliff_is_locked() {
+        let vesting = test_vesting();
+        assert_eq!(vesting.vested_amount(0), 0);
+        assert_eq!(vesting.vested_amount(199), 0);
+    }
+
+    #[test]
+    fn vesting_at_cliff_releases_elapsed_share() {
+        assert_eq!(test_vesting().vested_amount(200), 100);
+    }
+
+    #[test]
+    fn vesting_mid_schedule_is_linear() {
+        let vesting = test_vesting();
+        assert_eq!(vesting.vested_amount(600), 500);
+        assert_eq!(vesting.vested_amount(1_099), 999);
+    }
+
+    #[test]
+    fn vesting_after_end_releases_everything() {
+        let vesting = test_vesting();
+        assert_eq!(vesting.vested_amount(1_100), 1_000);
+        assert_eq!(vesting.vested_amount(i64::MAX), 1_000);
+    }
+
+    #[test]
+    fn vesting_claimable_excludes_claimed() {
+        let mut vesting = test_vesting();
+        vesting.claimed_amount = 400;
+        assert_eq!(vesting.claimable_amount(600), 100);
+        assert_eq!(vesting.claimable_amount(300), 0);
+    }
+
+    fn test_distributor(num_recipients: u32) -> Distributor {
+        Distributor {
+            version: Distributor::VERSION,
+            mint: Pubkey::new_unique(),
+            merkle_root: [0; 32],
+            total_amount: 0,
+            claimed_amount: 0,
+            num_recipients,
+            num_claimed: 0,
+            expiry_ts: 0,
+            clawed_back: false,
+            bump: 0,
+            padding: [0; 32],
+            claimed_bitmap: vec![0; Distributor::bitmap_len(num_recipients)],
+        }
+    }
+
+    #[test]
+    fn distributor_bitmap_len_rounds_up() {
+        assert_eq!(Distributor::bitmap_len(1), 1);
+        assert_eq!(Distributor::bitmap_len(8), 1);
+        assert_eq!(Distributor::bitmap_len(9), 2);
+        assert_eq!(Distributor::bitmap_len(Distributor::MAX_RECIPIENTS), 8_192);
+    }
+
+    #[test]
+    fn distributor_claims_across_byte_boundary() {
+        let mut distributor = test_distributor(16);
+        distributor.set_claimed(7);
+        distributor.set_claimed(8);
+
+        assert!(distributor.is_claimed(7));
+        assert!(distributor.is_claimed(8));
+        for index in (0..16).filter(|index| *index != 7 && *index != 8) {
+            assert!(!distributor.is_claimed(index), "index {index} claimed");
+        }
+        assert_eq!(distributor.claimed_bitmap, vec![0b1000_0000, 0b0000_0001]);
+    }
+
+    #[test]
+    fn distributor_claims_last_index() {
+        let last = Distributor::MAX_RECIPIENTS - 1;
+        let mut distributor = test_distributor(Distributor::MAX_RECIPIENTS);
+        assert!(!distributor.is_claimed(last));
+
+        distributor.set_claimed(last);
+        assert!(distributor.is_claimed(last));
+        assert!(!distributor.is_claimed(last - 1));
+        assert_eq!(distributor.claimed_bitmap.last(), Some(&0b1000_0000));
+    }
+
+    #[test]
+    fn distributor_set_claimed_is_idempotent() {
+        let mut distributor = test_distributor(8);
+        distributor.set_claimed(3);
+        distributor.set_claimed(3);
+        assert_eq!(distributor.claimed_bitmap, vec![0b0000_1000]);
+    }
+
+    fn rate_limited_config(window: i64, max_launches: u32, cooldown: i64) -> Config {
+        let mut config = test_config();
+        config.launch_window = window;
+        config.max_launches_per_window = max_launches;
+        config.launch_cooldown = cooldown;
+        config
+    }
+
+    #[test]
+    fn record_launch_caps_launches_per_window() {
+        let config = rate_limited_config(100, 2, 0);
+        let creator = Pubkey::new_unique();
+        let mut profile = CreatorProfile::default();
+
+        profile.record_launch(creator, &config, 1_000).unwrap();
+        profile.record_launch(creator, &config, 1_050).unwrap();
+        assert_error(
+            profile.record_launch(creator, &config, 1_099),
+            PumpfunError::LaunchRateLimited,
+        );
+
+        //  a rejected launch leaves the profile untouched
+        assert_eq!(profile.launch_count, 2);
+        assert_eq!(profile.window_launch_count, 2);
+        assert_eq!(profile.window_start_ts, 1_000);
+        assert_eq!(profile.last_launch_ts, 1_050);
+        assert_eq!(profile.creator, creator);
+    }
+
+    #[test]
+    fn record_launch_resets_window_at_boundary() {
+        let config = rate_limited_config(100, 2, 0);
+        let creator = Pubkey::new_unique();
+        let mut profile = CreatorProfile::default();
+
+        profile.record_launch(creator, &config, 1_000).unwrap();
+        profile.record_launch(creator, &config, 1_001).unwrap();
+
+        //  the window is half open, so its end starts a new one
+        profile.record_launch(creator, &config, 1_100).unwrap();
+        assert_eq!(profile.window_start_ts, 1_100);
+        assert_eq!(profile.window_launch_count, 1);
+        assert_eq!(profile.launch_count, 3);
+    }
+
+    #[test]
+    fn record_launch_resets_counter_after_window() {
+        let config = rate_limited_config(100, 1, 0);
+        let creator = Pubkey::new_unique();
+        let mut profile = CreatorProfile::default();
+
+        profile.record_launch(creator, &config, 1_000).unwrap();
+        assert_error(
+            profile.record_launch(creator, &config, 1_099),
+            PumpfunError::LaunchRateLimited,
+        );
+
+        profile.record_launch(creator, &config, 1_500).unwrap();
+        assert_eq!(profile.window_start_ts, 1_500);
+        assert_eq!(profile.window_launch_count, 1);
+        assert_eq!(profile.launch_count, 2);
+    }
+
+    #[test]
+    fn record_launch_without_cap_only_counts() {
+        let config = rate_limited_config(100, 0, 0);
+        let creator = Pubkey::new_unique();
+        let mut profile = CreatorProfile::default();
+
+        for now in 1_000..1_010 {
+            profile.record_launch(creator, &config, now).unwrap();
+        }
+        assert_eq!(profile.window_launch_count, 10);
+        assert_eq!(profile.launch_count, 10);
+    }
+
+    #[test]
+    fn record_launch_enforces_cooldown() {
+        let config = rate_limited_config(0, 0, 60);
+        let creator = Pubkey::new_unique();
+        let mut profile = CreatorProfile::default();
+
+        profile.record_launch(creator, &config, 1_000).unwrap();
+        assert_error(
+            profile.record_launch(creator, &config, 1_059),
+            PumpfunError::LaunchCooldown,
+        );
+        profile.record_launch(creator, &config, 1_060).unwrap();
+        assert_eq!(profile.launch_count, 2);
+        assert_eq!(profile.window_launch_count, 0);
+    }
+
+    fn graduate_profile(data: &mut [u8], owner: &Pubkey) -> CreatorProfile {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let info = AccountInfo::new(&key, false, true, &mut lamports, data, owner, false, 0);
+        CreatorProfile::record_graduation(&info).unwrap();
+        CreatorProfile::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn record_graduation_skips_foreign_profiles() {
+        let profile = CreatorProfile {
+            launch_count: 1,
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        profile.try_serialize(&mut data).unwrap();
+
+        let profile = graduate_profile(&mut data, &Pubkey::new_unique());
+        assert_eq!(profile.graduated_count, 0);
+
+        let profile = graduate_profile(&mut data, &crate::ID);
+        assert_eq!(profile.graduated_count, 1);
+        assert_eq!(profile.launch_count, 1);
+    }
+
+    #[test]
+    fn validate_rejects_curve_limit_at_max_init_reserve() {
+        let mut config = test_config();
+        config.lamport_amount_config = AmountConfig::Range {
+            min: None,
+            max: Some(config.curve_limit - 1),
+        };
+        config.validate().unwrap();
+
+        config.lamport_amount_config = AmountConfig::Range {
+            min: None,
+            max: Some(config.curve_limit),
+        };
+        assert_error(config.validate(), PumpfunError::CurveLimitBelowReserve);
+
+        config.lamport_amount_config = AmountConfig::Enum(vec![1, config.curve_limit + 1, 2]);
+        assert_error(config.validate(), PumpfunError::CurveLimitBelowReserve);
+    }
+
+    #[test]
+    fn quote_config_rejects_curve_limit_at_max_init_reserve() {
+        let mut quote_config = QuoteConfig {
+            version: QuoteConfig::VERSION,
+            mint: Pubkey::new_unique(),
+            enabled: true,
+            curve_limit: 1_000,
+            min_init_reserve: 10,
+            max_init_reserve: 999,
+            padding: [0; 64],
+        };
+        quote_config.validate().unwrap();
+
+        quote_config.curve_limit = 999;
+        assert_error(
+            quote_config.validate(),
+            PumpfunError::CurveLimitBelowReserve,
+        );
+    }
+
+    #[test]
+    fn amount_config_upper_bound() {
+        let open: AmountConfig<u64> = AmountConfig::Range {
+            min: Some(1),
+            max: None,
+        };
+        assert_eq!(open.upper_bound(), None);
+        let options = AmountConfig::Enum(vec![3_u64, 9, 4]);
+        assert_eq!(options.upper_bound(), Some(&9));
+    }
+
+    #[test]
+    fn transfer_fee_params_are_capped() {
+        let mut params = TransferFeeParams {
+            basis_points: MAX_TRANSFER_FEE_BPS,
+            maximum_fee: u64::MAX,
+        };
+        params.validate().unwrap();
+
+        params.basis_points = MAX_TRANSFER_FEE_BPS + 1;
+        assert_error(params.validate(), PumpfunError::TransferFeeTooHigh);
+    }
+
+    #[test]
+    fn validate_accepts_zero_migration_costs() {
+        let mut config = test_config();
+        assert_eq!(
+            config.migration_cost_lamports,
+            Config::DEFAULT_MIGRATION_COST_LAMPORTS
+        );
+
+        config.migration_cost_lamports = 0;
+        config.market_cost_lamports = 0;
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn validate_rejects_market_cost_above_migration_cost() {
+        let mut config = test_config();
+        config.migration_cost_lamports = 100;
+        config.market_cost_lamports = 101;
+        assert_error(config.validate(), PumpfunError::InvalidMigrationCost);
+    }
+
+    fn metadata_rules() -> MetadataRules {
+        MetadataRules {
+            max_name_len: 8,
+            max_symbol_len: 4,
+            max_uri_len: 40,
+            ascii_symbol_only: true,
+            uri_prefixes: vec!["https://arweave.net/".to_string(), "ipfs://".to_string()],
+        }
+    }
+
+    #[test]
+    fn metadata_rules_check_name_length() {
+        let rules = metadata_rules();
+        rules.check_name("12345678").unwrap();
+        assert_error(rules.check_name("123456789"), PumpfunError::NameTooLong);
+        assert_error(rules.check_name(""), PumpfunError::NameTooLong);
+    }
+
+    #[test]
+    fn metadata_rules_check_symbol() {
+        let rules = metadata_rules();
+        rules.check_symbol("PUMP").unwrap();
+        assert_error(rules.check_symbol("PUMPS"), PumpfunError::SymbolTooLong);
+        assert_error(rules.check_symbol(""), PumpfunError::SymbolTooLong);
+        assert_error(rules.check_symbol("P P"), PumpfunError::SymbolNotAscii);
+        assert_error(rules.check_symbol("é"), PumpfunError::SymbolNotAscii);
+
+        let rules = MetadataRules {
+            ascii_symbol_only: false,
+            ..metadata_rules()
+        };
+        rules.check_symbol("é").unwrap();
+    }
+
+    #[test]
+    fn metadata_rules_check_uri() {
+        let rules = metadata_rules();
+        rules.check_uri("https://arweave.net/abc").unwrap();
+        rules.check_uri("ipfs://abc").unwrap();
+        assert_error(
+            rules.check_uri("https://example.com/abc"),
+            PumpfunError::UriPrefixNotAllowed,
+        );
+        assert_error(
+            rules.check_uri(&format!("ipfs://{}", "a".repeat(34))),
+            PumpfunError::UriTooLong,
+        );
+    }
+
+    #[test]
+    fn metadata_rules_default_to_metaplex_maximums() {
+        let rules = MetadataRules::default();
+        rules
+            .check(
+                &"n".repeat(MAX_NAME_LENGTH),
+                &"S".repeat(MAX_SYMBOL_LENGTH),
+                &"u".repeat(MAX_URI_LENGTH),
+            )
+            .unwrap();
+        assert_error(
+            rules.check_name(&"n".repeat(MAX_NAME_LENGTH + 1)),
+            PumpfunError::NameTooLong,
+        );
+        assert_error(
+            rules.check_symbol(&"S".repeat(MAX_SYMBOL_LENGTH + 1)),
+            PumpfunError::SymbolTooLong,
+        );
+        assert_error(
+            rules.check_uri(&"u".repeat(MAX_URI_LENGTH + 1)),
+            PumpfunError::UriTooLong,
+        );
+        //  no prefixes allow any uri and non-ascii symbols are allowed
+        rules.check_uri("data:abc").unwrap();
+        rules.check_symbol("é").unwrap();
+    }
+
+    #[test]
+    fn metadata_rules_validate() {
+        metadata_rules().validate().unwrap();
+
+        let rules = MetadataRules {
+            max_symbol_len: MAX_SYMBOL_LENGTH as u8 + 1,
+            ..metadata_rules()
+        };
+        assert_error(rules.validate(), PumpfunError::InvalidMetadataRules);
+
+        let rules = MetadataRules {
+            uri_prefixes: vec![String::new()],
+            ..metadata_rules()
+        };
+        assert_error(rules.validate(), PumpfunError::InvalidMetadataRules);
+
+        let rules = MetadataRules {
+            uri_prefixes: vec!["ipfs://".to_string(); MetadataRules::MAX_URI_PREFIXES + 1],
+            ..metadata_rules()
+        };
+        assert_error(rules.validate(), PumpfunError::InvalidMetadataRules);
+    }
+
+    #[test]
+    fn bonding_curve_from_v1_keeps_fields() {
+        let mut bonding_curve = BondingCurve::from(BondingCurveV0 {
+            token_mint: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            init_lamport: 30,
+            reserve_lamport: 40,
+            reserve_token: 50,
+            is_completed: true,
+        });
+        bonding_curve.version = 1;
+        bonding_curve.crank_reward = 60;
+        bonding_curve.migration_target = MigrationTarget::MeteoraDamm;
+
+        let data = bonding_curve.try_to_vec().unwrap();
+        assert_eq!(data.len(), BondingCurve::INIT_SPACE);
+        let v1 = &data[..BondingCurve::V1_SPACE];
+
+        let migrated = BondingCurve::from_v1(v1).unwrap();
+        assert_eq!(migrated.version, BondingCurve::VERSION);
+        assert_eq!(migrated.token_mint, bonding_curve.token_mint);
+        assert_eq!(migrated.reserve_token, 50);
+        assert_eq!(migrated.crank_reward, 60);
+        assert_eq!(migrated.migration_target, MigrationTarget::MeteoraDamm);
+        assert_eq!(migrated.padding, [0; 128]);
+
+        assert_error(
+            BondingCurve::from_v1(&v1[..v1.len() - 1]).map(|_| ()),
+            PumpfunError::UnsupportedLayoutVersion,
+        );
+    }
+
+    fn registered_curve(registry_index: u64) -> BondingCurve {
+        let mut bonding_curve = BondingCurve::from(BondingCurveV0 {
+            token_mint: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            init_lamport: 0,
+            reserve_lamport: 0,
+            reserve_token: 0,
+            is_completed: false,
+        });
+        bonding_curve.is_registered = true;
+        bonding_curve.registry_index = registry_index;
+        bonding_curve
+    }
+
+    fn registry_page(index: u32, mints: &[Pubkey]) -> RegistryPage {
+        RegistryPage {
+            version: RegistryPage::VERSION,
+            index,
+            entries: mints
+                .iter()
+                .map(|mint| RegistryEntry {
+                    mint: *mint,
+                    bonding_curve: Pubkey::new_unique(),
+                    creator: Pubkey::new_unique(),
+                    launch_slot: 0,
+                    status: LaunchStatus::Trading,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn registry_set_status_updates_the_curve_entry() {
+        let bonding_curve = registered_curve(RegistryPage::PAGE_SIZE + 1);
+        let mut page = registry_page(1, &[Pubkey::new_unique(), bonding_curve.token_mint]);
+
+        RegistryPage::set_status(Some(&mut page), &bonding_curve, LaunchStatus::Migrated).unwrap();
+        assert_eq!(page.entries[1].status, LaunchStatus::Migrated);
+        assert_eq!(page.entries[0].status, LaunchStatus::Trading);
+    }
+
+    #[test]
+    fn registry_set_status_rejects_wrong_page() {
+        let bonding_curve = registered_curve(RegistryPage::PAGE_SIZE + 1);
+
+        let mut page = registry_page(0, &[Pubkey::new_unique(), bonding_curve.token_mint]);
+        assert_error(
+            RegistryPage::set_status(Some(&mut page), &bonding_curve, LaunchStatus::Migrated),
+            PumpfunError::InvalidRegistryPage,
+        );
+
+        //  shorter page, the entry of the curve is missing
+        let mut page = registry_page(1, &[Pubkey::new_unique()]);
+        assert_error(
+            RegistryPage::set_status(Some(&mut page), &bonding_curve, LaunchStatus::Migrated),
+            PumpfunError::InvalidRegistryPage,
+        );
+
+        //  entry at the index belongs to another mint
+        let mut page = registry_page(1, &[Pubkey::new_unique(), Pubkey::new_unique()]);
+        assert_error(
+            RegistryPage::set_status(Some(&mut page), &bonding_curve, LaunchStatus::Migrated),
+            PumpfunError::InvalidRegistryPage,
+        );
+
+        assert_error(
+            RegistryPage::set_status(None, &bonding_curve, LaunchStatus::Migrated),
+            PumpfunError::MissingRegistryPage,
+        );
+    }
+}
@@ -1,6 +1,23 @@
-use crate::*;
-use anchor_spl::token::{self, Token};
-use solana_program::program::{invoke, invoke_signed};
+use crate::{constants::MAX_TRANSFER_FEE_BPS, errors::PumpfunError, *};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id},
+    token_2022::{
+        self,
+        spl_token_2022::{
+            self,
+            extension::{
+                metadata_pointer::MetadataPointer, transfer_fee::TransferFeeConfig,
+                BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+            },
+        },
+    },
+    token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata,
+    token_interface::{self, Mint, TokenInterface},
+};
+use solana_program::{
+    keccak,
+    program::{invoke, invoke_signed},
+};
 use std::ops::{Div, Mul};
 
 pub fn convert_to_float(value: u64, decimals: u8) -> f64 {
@@ -11,6 +28,39 @@ pub fn convert_from_float(value: f64, decimals: u8) -> u64 {
     value.mul(f64::powf(10.0, decimals as f64)) as u64
 }
 
+//  basis point share of an amount, rounding down
+pub fn bps_of(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / 10_000) as u64
+}
+
+//  leaf of a merkle tree over wallet addresses
+pub fn merkle_leaf(wallet: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[wallet.as_ref()]).to_bytes()
+}
+
+//  leaf of an airdrop merkle tree
+pub fn airdrop_leaf(index: u32, recipient: &Pubkey, amount: u64) -> [u8; 32] {
+    keccak::hashv(&[
+        &index.to_le_bytes(),
+        recipient.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+//  verify a keccak merkle proof, pairs are hashed in sorted order
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+    let mut computed = *leaf;
+    for node in proof {
+        computed = if computed <= *node {
+            keccak::hashv(&[&computed, node]).to_bytes()
+        } else {
+            keccak::hashv(&[node, &computed]).to_bytes()
+        };
+    }
+    computed == *root
+}
+
 //  transfer sol from user
 pub fn sol_transfer_from_user<'info>(
     signer: &Signer<'info>,
@@ -47,23 +97,44 @@ pub fn sol_transfer_with_signer<'info>(
     Ok(())
 }
 
+//  grow a program owned account, topping up rent from the payer
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let lamport_delta = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if lamport_delta > 0 {
+        sol_transfer_from_user(payer, account.clone(), system_program, lamport_delta)?;
+    }
+    if new_len > account.data_len() {
+        account.realloc(new_len, false)?;
+    }
+    Ok(())
+}
+
 //  transfer token from user
 pub fn token_transfer_user<'info>(
     from: AccountInfo<'info>,
     authority: &Signer<'info>,
     to: AccountInfo<'info>,
-    token_program: &Program<'info, Token>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
     amount: u64,
 ) -> Result<()> {
     let cpi_ctx: CpiContext<_> = CpiContext::new(
         token_program.to_account_info(),
-        token::Transfer {
+        token_interface::TransferChecked {
             from,
+            mint: mint.to_account_info(),
             authority: authority.to_account_info(),
             to,
         },
     );
-    token::transfer(cpi_ctx, amount)?;
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)?;
 
     Ok(())
 }
@@ -73,20 +144,240 @@ pub fn token_transfer_with_signer<'info>(
     from: AccountInfo<'info>,
     authority: AccountInfo<'info>,
     to: AccountInfo<'info>,
-    token_program: &Program<'info, Token>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
     signer_seeds: &[&[&[u8]]],
     amount: u64,
 ) -> Result<()> {
     let cpi_ctx: CpiContext<_> = CpiContext::new_with_signer(
         token_program.to_account_info(),
-        token::Transfer {
+        token_interface::TransferChecked {
             from,
+            mint: mint.to_account_info(),
             to,
             authority,
         },
         signer_seeds,
     );
-    token::transfer(cpi_ctx, amount)?;
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)?;
 
     Ok(())
 }
+
+//  check the account is the associated token account of owner for mint
+pub fn check_ata_address(
+    ata: &AccountInfo,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<()> {
+    let expected = get_associated_token_address_with_program_id(owner, mint, token_program);
+    if ata.key() != expected {
+        msg!("expected associated token account {expected:?}, got {:?}", ata.key());
+        return err!(PumpfunError::InvalidQuoteAccount);
+    }
+    Ok(())
+}
+
+//  create associated token account, if it doesn't exist
+pub fn create_ata_if_needed<'info>(
+    payer: AccountInfo<'info>,
+    ata: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    associated_token_program: AccountInfo<'info>,
+) -> Result<()> {
+    if ata.data_is_empty() {
+        associated_token::create(CpiContext::new(
+            associated_token_program,
+            associated_token::Create {
+                payer,
+                associated_token: ata,
+                authority,
+                mint,
+                system_program,
+                token_program,
+            },
+        ))?;
+    }
+    Ok(())
+}
+
+//  token-2022 transfer fee withheld from a transfer of `amount`, 0 for spl token mints
+pub fn transfer_fee_of(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    if mint_info.owner != &token_2022::ID {
+        return Ok(0);
+    }
+
+    let data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let fee = match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(fee_config) => fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?,
+        Err(_) => 0,
+    };
+    Ok(fee)
+}
+
+//  extensions a listed token-2022 mint may have, with their authorities revoked
+//  any other extension could drain, freeze or block transfers of the curve tokens
+pub fn check_listed_mint_extensions(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    if mint_info.owner != &token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    for extension in mint_state.get_extension_types()? {
+        let authorities = match extension {
+            ExtensionType::MetadataPointer => {
+                vec![mint_state.get_extension::<MetadataPointer>()?.authority]
+            }
+            ExtensionType::TokenMetadata => {
+                vec![
+                    mint_state
+                        .get_variable_len_extension::<TokenMetadata>()?
+                        .update_authority,
+                ]
+            }
+            ExtensionType::TransferFeeConfig => {
+                let fee_config = mint_state.get_extension::<TransferFeeConfig>()?;
+                for fee in [fee_config.older_transfer_fee, fee_config.newer_transfer_fee] {
+                    let basis_points = u16::from(fee.transfer_fee_basis_points);
+                    if basis_points > MAX_TRANSFER_FEE_BPS {
+                        msg!(
+                            "transfer fee {basis_points:?} exceeds {:?} basis points",
+                            MAX_TRANSFER_FEE_BPS
+                        );
+                        return err!(PumpfunError::TransferFeeTooHigh);
+                    }
+                }
+                vec![
+                    fee_config.transfer_fee_config_authority,
+                    fee_config.withdraw_withheld_authority,
+                ]
+            }
+            _ => {
+                msg!("unsupported mint extension {:?}", extension);
+                return err!(PumpfunError::UnsupportedMintExtension);
+            }
+        };
+
+        if authorities
+            .into_iter()
+            .any(|authority| Option::<Pubkey>::from(authority).is_some())
+        {
+            msg!("authority of mint extension {:?} is still set", extension);
+            return err!(PumpfunError::MintExtensionAuthorityEnabled);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[a, b]).to_bytes()
+        } else {
+            keccak::hashv(&[b, a]).to_bytes()
+        }
+    }
+
+    //  four leaf tree, returns the root and the proof of every leaf
+    fn build_tree(leaves: &[[u8; 32]; 4]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let left = hash_pair(&leaves[0], &leaves[1]);
+        let right = hash_pair(&leaves[2], &leaves[3]);
+        let root = hash_pair(&left, &right);
+        let proofs = vec![
+            vec![leaves[1], right],
+            vec![leaves[0], right],
+            vec![leaves[3], left],
+            vec![leaves[2], left],
+        ];
+        (root, proofs)
+    }
+
+    fn wallet_leaves() -> [[u8; 32]; 4] {
+        [0; 4].map(|_| merkle_leaf(&Pubkey::new_unique()))
+    }
+
+    #[test]
+    fn merkle_proof_verifies_every_leaf() {
+        let leaves = wallet_leaves();
+        let (root, proofs) = build_tree(&leaves);
+        for (leaf, proof) in leaves.iter().zip(proofs.iter()) {
+            assert!(verify_merkle_proof(proof, &root, leaf));
+        }
+    }
+
+    #[test]
+    fn merkle_proof_is_order_independent() {
+        //  sorted pairs, so swapping siblings gives the same root
+        let leaves = wallet_leaves();
+        let swapped = [leaves[1], leaves[0], leaves[3], leaves[2]];
+        assert_eq!(build_tree(&leaves).0, build_tree(&swapped).0);
+    }
+
+    #[test]
+    fn merkle_proof_rejects_wrong_leaf() {
+        let leaves = wallet_leaves();
+        let (root, proofs) = build_tree(&leaves);
+        let outsider = merkle_leaf(&Pubkey::new_unique());
+        assert!(!verify_merkle_proof(&proofs[0], &root, &outsider));
+        assert!(!verify_merkle_proof(&proofs[0], &root, &leaves[2]));
+    }
+
+    #[test]
+    fn merkle_proof_rejects_wrong_proof() {
+        let leaves = wallet_leaves();
+        let (root, proofs) = build_tree(&leaves);
+        let mut tampered = proofs[0].clone();
+        tampered[1][0] ^= 1;
+        assert!(!verify_merkle_proof(&tampered, &root, &leaves[0]));
+        assert!(!verify_merkle_proof(&proofs[0][..1], &root, &leaves[0]));
+        assert!(!verify_merkle_proof(&[], &root, &leaves[0]));
+    }
+
+    #[test]
+    fn merkle_proof_of_single_leaf_tree_is_empty() {
+        let leaf = merkle_leaf(&Pubkey::new_unique());
+        assert!(verify_merkle_proof(&[], &leaf, &leaf));
+    }
+
+    #[test]
+    fn airdrop_proof_binds_index_recipient_and_amount() {
+        let recipients = [0; 4].map(|_| Pubkey::new_unique());
+        let leaves: [[u8; 32]; 4] =
+            core::array::from_fn(|index| airdrop_leaf(index as u32, &recipients[index], 1_000));
+        let (root, proofs) = build_tree(&leaves);
+
+        assert!(verify_merkle_proof(
+            &proofs[2],
+            &root,
+            &airdrop_leaf(2, &recipients[2], 1_000)
+        ));
+        assert!(!verify_merkle_proof(
+            &proofs[2],
+            &root,
+            &airdrop_leaf(2, &recipients[2], 1_001)
+        ));
+        assert!(!verify_merkle_proof(
+            &proofs[2],
+            &root,
+            &airdrop_leaf(3, &recipients[2], 1_000)
+        ));
+        assert!(!verify_merkle_proof(
+            &proofs[2],
+            &root,
+            &airdrop_leaf(2, &recipients[3], 1_000)
+        ));
+    }
+}